@@ -5,7 +5,7 @@ use embedded_graphics_simulator::{
     OutputSettingsBuilder, SimulatorDisplay, SimulatorEvent, Window,
 };
 use lvgl::style::Style;
-use lvgl::widgets::{Arc, Label, LabelAlign};
+use lvgl::widgets::{Arc, Label, LabelAlign, LabelPart};
 use lvgl::{self, Align, Color, Part, State, UI};
 use lvgl::{LvError, Widget};
 use lvgl_sys;
@@ -71,7 +71,7 @@ fn run_arc_demo() -> Result<(), LvError> {
 
     let mut loading_style = Style::default();
     loading_style.set_text_color(State::DEFAULT, Color::from_rgb((0, 0, 0)));
-    loading_lbl.add_style(Part::Main, loading_style)?;
+    loading_lbl.add_style(LabelPart::Main, loading_style)?;
 
     let mut angle = 0;
     let mut forward = true;
@@ -5,7 +5,7 @@ use embedded_graphics_simulator::{
     OutputSettingsBuilder, SimulatorDisplay, SimulatorEvent, Window,
 };
 use lvgl::style::Style;
-use lvgl::widgets::{Bar, Label, LabelAlign};
+use lvgl::widgets::{Bar, BarPart, Label, LabelAlign, LabelPart};
 use lvgl::{self, Align, Animation, Color, Event, LvError, Part, State, Widget, UI};
 use lvgl_sys;
 use std::time::Instant;
@@ -44,7 +44,7 @@ fn main() -> Result<(), LvError> {
     // // Set the indicator style for the bar object
     let mut ind_style = Style::default();
     ind_style.set_bg_color(State::DEFAULT, Color::from_rgb((100, 245, 100)));
-    bar.add_style(Part::All, ind_style)?;
+    bar.add_style(BarPart::All, ind_style)?;
 
     let mut loading_lbl = Label::new(&mut screen)?;
     loading_lbl.set_text(CString::new("Loading...").unwrap().as_c_str())?;
@@ -53,7 +53,7 @@ fn main() -> Result<(), LvError> {
 
     let mut loading_style = Style::default();
     loading_style.set_text_color(State::DEFAULT, Color::from_rgb((0, 0, 0)));
-    loading_lbl.add_style(Part::Main, loading_style)?;
+    loading_lbl.add_style(LabelPart::Main, loading_style)?;
 
     let mut i = 0;
     let mut loop_started = Instant::now();
@@ -4,11 +4,11 @@ use embedded_graphics::prelude::*;
 use embedded_graphics_simulator::{
     OutputSettingsBuilder, SimulatorDisplay, SimulatorEvent, Window,
 };
-use lvgl::style::Style;
-use lvgl::widgets::{Btn, Label};
-use lvgl::{self, Align, Color, Event, LvError, Part, State, Widget, UI};
+use lvgl::style::{Style, StyleProp, Transition};
+use lvgl::widgets::{Btn, BtnPart, Label};
+use lvgl::{self, Align, AnimPath, Color, Event, LvError, Part, State, Widget, UI};
 use lvgl_sys;
-use std::time::Instant;
+use std::time::{Duration, Instant};
 
 fn main() -> Result<(), LvError> {
     let display: SimulatorDisplay<Rgb565> = SimulatorDisplay::new(Size::new(
@@ -35,11 +35,23 @@ fn main() -> Result<(), LvError> {
     let mut button = Btn::new(&mut screen)?;
     button.set_align(&mut screen, Align::InLeftMid, 30, 0)?;
     button.set_size(180, 80)?;
+
+    // Smoothly change the button color when it is pressed
+    let mut btn_style = Style::default();
+    btn_style.set_bg_color(State::PRESSED, Color::from_rgb((100, 245, 100)));
+    btn_style.set_transition(
+        State::DEFAULT,
+        Transition::new(&[StyleProp::BG_COLOR])?
+            .duration(Duration::from_millis(300))
+            .path(&AnimPath::EASE_OUT),
+    );
+    button.add_style(BtnPart::Main, btn_style)?;
+
     let mut btn_lbl = Label::new(&mut button)?;
     btn_lbl.set_text(CString::new("Click me!").unwrap().as_c_str())?;
 
     let mut btn_state = false;
-    button.on_event(|mut btn, event| {
+    button.on_event(move |mut btn, event| {
         if let lvgl::Event::Clicked = event {
             if btn_state {
                 let nt = CString::new("Click me!").unwrap();
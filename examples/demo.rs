@@ -6,8 +6,8 @@ use embedded_graphics_simulator::{
 };
 use lvgl;
 use lvgl::style::Style;
-use lvgl::widgets::{Label, LabelAlign};
-use lvgl::{Align, Color, LvError, Part, State, Widget, UI};
+use lvgl::widgets::{Label, LabelAlign, LabelPart};
+use lvgl::{Align, Color, Font, LvError, Part, State, Widget, UI};
 use lvgl_sys;
 use std::thread::sleep;
 use std::time::{Duration, Instant};
@@ -36,9 +36,12 @@ fn main() -> Result<(), LvError> {
 
     let mut time = Label::new(&mut screen)?;
     let mut style_time = Style::default();
-    //style_time.set_text_font(font_noto_sans_numeric_28);
+    style_time.set_text_font(
+        State::DEFAULT,
+        Font::from_raw(unsafe { &noto_sans_numeric_80 }),
+    );
     style_time.set_text_color(State::DEFAULT, Color::from_rgb((255, 255, 255)));
-    time.add_style(Part::Main, style_time)?;
+    time.add_style(LabelPart::Main, style_time)?;
     time.set_align(&mut screen, Align::Center, 0, 0)?;
     time.set_text(CString::new("20:46").unwrap().as_c_str())?;
     time.set_width(240)?;
@@ -89,13 +92,6 @@ fn main() -> Result<(), LvError> {
 }
 
 // Reference to native font for LVGL, defined in the file: "fonts_noto_sans_numeric_80.c"
-// TODO: Create a macro for defining a safe wrapper for fonts.
-// Maybe sometihng like:
-//
-// font_declare! {
-//     NotoSansNumeric80 = noto_sans_numeric_80;
-// };
-//
 extern "C" {
-    pub static mut noto_sans_numeric_80: lvgl_sys::lv_font_t;
+    pub static noto_sans_numeric_80: lvgl_sys::lv_font_t;
 }
@@ -4,7 +4,7 @@ use embedded_graphics_simulator::{
     OutputSettingsBuilder, SimulatorDisplay, SimulatorEvent, Window,
 };
 use lvgl::style::{Opacity, Style};
-use lvgl::widgets::Gauge;
+use lvgl::widgets::{Gauge, GaugePart};
 use lvgl::{self, Align, Color, LvError, Part, State, Widget, UI};
 use lvgl_sys;
 use std::time::Instant;
@@ -50,7 +50,7 @@ fn main() -> Result<(), LvError> {
     gauge_style.set_scale_end_border_width(State::DEFAULT, 4);
 
     let mut gauge = Gauge::new(&mut screen)?;
-    gauge.add_style(Part::Main, gauge_style)?;
+    gauge.add_style(GaugePart::Main, gauge_style)?;
     gauge.set_align(&mut screen, Align::Center, 0, 0)?;
     gauge.set_value(0, 50)?;
 
@@ -4,30 +4,230 @@ use proc_macro2::{Ident, TokenStream};
 use quote::quote;
 use quote::{format_ident, ToTokens};
 use regex::Regex;
-use std::collections::HashMap;
+use std::collections::{BTreeMap, HashMap};
 use std::error::Error;
-use syn::{FnArg, ForeignItem, ForeignItemFn, Item, ReturnType};
+use std::fmt;
+use std::fs::File;
+use std::io::{self, Write};
+use std::path::{Path, PathBuf};
+use syn::{FnArg, ForeignItem, ForeignItemFn, Item, ItemConst, ReturnType};
 
 type CGResult<T> = Result<T, Box<dyn Error>>;
 
 const LIB_PREFIX: &str = "lv_";
+const STYLE_PROP_PREFIX: &str = "LV_STYLE_";
+const LV_OBJ_PART_ALL: u32 = 0xFF;
 
 lazy_static! {
     static ref TYPE_MAPPINGS: HashMap<&'static str, &'static str> = [
         ("u16", "u16"),
         ("i32", "i32"),
         ("u8", "u8"),
+        ("i8", "i8"),
+        ("i16", "i16"),
+        ("u32", "u32"),
         ("bool", "bool"),
+        ("lv_coord_t", "i16"),
+        ("lv_style_int_t", "i16"),
+        ("lv_anim_value_t", "i16"),
+        ("lv_opa_t", "u8"),
         ("* const cty :: c_char", "_"),
     ]
     .iter()
     .cloned()
     .collect();
+    // Native types with a safe Rust type, which converts `Into` the native one.
+    static ref WRAPPER_TYPE_MAPPINGS: HashMap<&'static str, &'static str> = [
+        ("lv_color_t", "crate::Color"),
+        ("lv_anim_enable_t", "crate::Animation"),
+        ("lv_align_t", "crate::Align"),
+        ("* const lv_font_t", "crate::Font"),
+    ]
+    .iter()
+    .cloned()
+    .collect();
+    // LVGL only stores a pointer for these properties, so each one needs a safe Rust type.
+    static ref STYLE_PTR_MAPPINGS: HashMap<&'static str, &'static str> = [
+        ("LV_STYLE_VALUE_STR", "str"),
+        ("LV_STYLE_VALUE_FONT", "font"),
+        ("LV_STYLE_TEXT_FONT", "font"),
+        ("LV_STYLE_PATTERN_IMAGE", "image"),
+        ("LV_STYLE_TRANSITION_PATH", "anim_path"),
+    ]
+    .iter()
+    .cloned()
+    .collect();
 }
 
-#[derive(Debug, Copy, Clone)]
+// Methods already provided by the `Widget` trait, a generated method would shadow them.
+const WIDGET_METHODS: [&str; 6] = [
+    "add_style",
+    "set_pos",
+    "set_size",
+    "set_width",
+    "set_height",
+    "set_align",
+];
+
+// Functions wrapped by hand in the `lvgl` crate, e.g. those taking a map of strings, which
+// LVGL reads until an empty string.
+const HAND_WRITTEN: [&str; 68] = [
+    "lv_label_set_align",
+    "lv_chart_add_series",
+    "lv_chart_add_cursor",
+    "lv_chart_set_update_mode",
+    "lv_chart_set_y_range",
+    "lv_chart_set_next",
+    "lv_chart_set_points",
+    "lv_chart_set_point_id",
+    "lv_chart_get_point_id",
+    "lv_chart_set_ext_array",
+    "lv_chart_init_points",
+    "lv_chart_set_point_count",
+    "lv_chart_clear_series",
+    "lv_chart_hide_series",
+    "lv_chart_set_series_axis",
+    "lv_chart_remove_series",
+    "lv_chart_set_cursor_point",
+    "lv_chart_get_cursor_point",
+    "lv_canvas_set_px",
+    "lv_canvas_get_px",
+    "lv_canvas_set_palette",
+    "lv_canvas_draw_rect",
+    "lv_canvas_draw_text",
+    "lv_canvas_draw_img",
+    "lv_canvas_draw_line",
+    "lv_canvas_draw_polygon",
+    "lv_canvas_draw_arc",
+    "lv_img_set_src",
+    "lv_imgbtn_set_src",
+    "lv_textarea_set_insert_replace",
+    "lv_textarea_set_text_align",
+    "lv_keyboard_set_textarea",
+    "lv_keyboard_get_textarea",
+    "lv_keyboard_set_map",
+    "lv_keyboard_set_ctrl_map",
+    "lv_btnmatrix_set_map",
+    "lv_btnmatrix_set_ctrl_map",
+    "lv_btnmatrix_set_btn_ctrl",
+    "lv_btnmatrix_clear_btn_ctrl",
+    "lv_btnmatrix_set_btn_ctrl_all",
+    "lv_btnmatrix_clear_btn_ctrl_all",
+    "lv_btnmatrix_get_btn_ctrl",
+    "lv_dropdown_set_options",
+    "lv_dropdown_add_option",
+    "lv_dropdown_get_selected",
+    "lv_roller_set_options",
+    "lv_roller_get_selected",
+    "lv_list_add_btn",
+    "lv_list_remove",
+    "lv_list_get_btn_index",
+    "lv_list_focus_btn",
+    "lv_list_get_btn_selected",
+    "lv_list_focus",
+    "lv_list_get_btn_text",
+    "lv_list_get_btn_label",
+    "lv_list_get_btn_img",
+    "lv_tabview_add_tab",
+    "lv_tabview_get_tab",
+    "lv_tabview_clean_tab",
+    "lv_tabview_set_tab_name",
+    "lv_tileview_get_tile_act",
+    "lv_anim_init",
+    "lv_anim_set_var",
+    "lv_anim_set_exec_cb",
+    "lv_anim_set_values",
+    "lv_anim_set_time",
+    "lv_anim_set_delay",
+    "lv_anim_set_path",
+];
+
+// Arguments LVGL keeps a pointer to instead of copying them, so they must be `'static`.
+const STATIC_ARGS: [(&str, &str); 9] = [
+    ("lv_label_set_text_static", "text"),
+    ("lv_checkbox_set_text_static", "txt"),
+    ("lv_dropdown_set_options_static", "options"),
+    ("lv_dropdown_set_text", "txt"),
+    ("lv_dropdown_set_symbol", "symbol"),
+    ("lv_textarea_set_accepted_chars", "list"),
+    ("lv_line_set_points", "point_a"),
+    ("lv_gauge_set_needle_count", "colors"),
+    ("lv_tileview_set_valid_positions", "valid_pos"),
+];
+
+// Widgets with events of their own, and the hand written type of these events.
+const WIDGET_EVENTS: [(&str, &str); 4] = [
+    ("btnmatrix", "BtnmatrixEvent"),
+    ("tabview", "TabviewEvent"),
+    ("textarea", "TextareaEvent"),
+    ("tileview", "TileviewEvent"),
+];
+
+// Native element types of array arguments with a `#[repr(C)]` Rust type of the same layout.
+const SLICE_ELEMENT_MAPPINGS: [(&str, &str); 2] = [
+    ("lv_point_t", "crate::Point"),
+    ("lv_color_t", "crate::Color"),
+];
+
+// Names of the integer arguments giving the length of an adjacent array argument.
+const SLICE_LEN_SUFFIXES: [&str; 4] = ["num", "cnt", "count", "len"];
+
+// Groups of widget constants turned into Rust enums: the constant prefix after the widget
+// name, the suffix of the Rust enum name and the suffix of the native type.
+const ENUM_GROUPS: [(&str, &str, &str); 8] = [
+    ("PART", "Part", "part_t"),
+    ("TYPE", "Type", "type_t"),
+    ("LONG", "LongMode", "long_mode_t"),
+    ("ALIGN", "Align", "align_t"),
+    ("MODE", "Mode", "mode_t"),
+    ("TAB_POS", "TabPos", "btns_pos_t"),
+    ("STATE", "State", "state_t"),
+    ("DIR", "Dir", "dir_t"),
+];
+
+// Non-widget LVGL modules: the function prefix, the native type of the receiver, the Rust type
+// and whether the Rust type is generated or written by hand. The `lv_img_*` functions of the
+// image widget are methods of `Img`, and the `lv_img_buf_*` functions are left out, they write
+// to the image data, which `ImageDescriptor` keeps read-only. `Anim` owns the animation it
+// configures, LVGL runs a copy of it.
+const MODULES: [(&str, &str, &str, bool); 8] = [
+    ("anim", "lv_anim_t", "Anim", false),
+    ("group", "lv_group_t", "Group", true),
+    ("disp", "lv_disp_t", "Display", true),
+    ("indev", "lv_indev_t", "InputDevice", true),
+    ("font", "lv_font_t", "Font", false),
+    ("task", "lv_task_t", "Task", true),
+    ("img_decoder", "lv_img_decoder_t", "ImageDecoder", true),
+    ("img_cache", "lv_img_cache_entry_t", "ImageCache", true),
+];
+
+// Containers which can also be created as a screen.
+const SCREEN_WIDGETS: [&str; 2] = ["cont", "page"];
+
+// Widgets owning memory that a copy can't share, their `new_copy` is written by hand.
+const HAND_WRITTEN_COPIES: [&str; 2] = ["btnmatrix", "canvas"];
+
+// Module functions releasing the receiver, wrapped as a `delete` method consuming the handle.
+const MODULE_DESTRUCTORS: [&str; 4] = ["del", "delete", "free", "remove"];
+
+#[derive(Debug, Clone, PartialEq)]
 pub enum WrapperError {
+    /// Not wrapped on purpose, e.g. the generic `Obj` or methods of the `Widget` trait.
     Skip,
+    /// The native type has no mapping to a Rust type yet.
+    UnmappedType(String),
+    /// The function is not a method of any widget, nor part of a module.
+    NotAWidgetMethod,
+}
+
+impl fmt::Display for WrapperError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            WrapperError::Skip => write!(f, "not wrapped on purpose"),
+            WrapperError::UnmappedType(typ) => write!(f, "unmapped type `{}`", typ),
+            WrapperError::NotAWidgetMethod => write!(f, "not a widget or module function"),
+        }
+    }
 }
 
 pub type WrapperResult<T> = Result<T, WrapperError>;
@@ -42,6 +242,30 @@ pub trait Rusty {
 pub struct LvWidget {
     name: String,
     methods: Vec<LvFunc>,
+    enums: Vec<LvEnum>,
+}
+
+impl LvWidget {
+    /// The default methods of the `Widget` trait, in a macro invoked inside the trait.
+    pub fn base_methods_code(&self) -> TokenStream {
+        let methods: Vec<TokenStream> = self
+            .methods
+            .iter()
+            .flat_map(|m| m.base_method_code(self))
+            .collect();
+        quote! {
+            macro_rules! widget_methods {
+                () => {
+                    #(#methods)*
+                };
+            }
+        }
+    }
+}
+
+/// The widget enum used in place of a native type, if there is one.
+fn enum_for<'a>(enums: &'a [LvEnum], typ: &LvType) -> Option<&'a LvEnum> {
+    enums.iter().find(|e| e.native_type == typ.literal_name)
 }
 
 impl Rusty for LvWidget {
@@ -54,9 +278,28 @@ impl Rusty for LvWidget {
         }
 
         let widget_name = format_ident!("{}", to_pascal_case(self.name.as_str()));
+        let enums: Vec<TokenStream> = self.enums.iter().flat_map(|e| e.code(self)).collect();
         let methods: Vec<TokenStream> = self.methods.iter().flat_map(|m| m.code(self)).collect();
+        let event = WIDGET_EVENTS
+            .iter()
+            .find(|(widget, _)| *widget == self.name)
+            .map(|(_, event)| format_ident!("{}", event));
+        let definition = match (self.enums.iter().find(|e| e.is_part()), event) {
+            (Some(part), Some(event)) => {
+                let part_name = part.ident();
+                quote!(define_object!(#widget_name, part = #part_name, event = #event);)
+            }
+            (Some(part), None) => {
+                let part_name = part.ident();
+                quote!(define_object!(#widget_name, part = #part_name);)
+            }
+            (None, Some(event)) => quote!(define_object!(#widget_name, event = #event);),
+            (None, None) => quote!(define_object!(#widget_name);),
+        };
         Ok(quote! {
-            define_object!(#widget_name);
+            #(#enums)*
+
+            #definition
 
             impl #widget_name {
                 #(#methods)*
@@ -65,16 +308,158 @@ impl Rusty for LvWidget {
     }
 }
 
+/// A Rust enum for a group of constants of a widget, e.g. `LV_BAR_PART_*`.
+#[derive(Clone)]
+pub struct LvEnum {
+    name: String,
+    native_type: String,
+    variants: Vec<LvEnumVariant>,
+}
+
+#[derive(Clone)]
+pub struct LvEnumVariant {
+    name: String,
+    const_name: String,
+    value: u32,
+}
+
+impl LvEnum {
+    pub fn ident(&self) -> Ident {
+        format_ident!("{}", self.name)
+    }
+
+    pub fn is_part(&self) -> bool {
+        self.native_type.ends_with("_part_t")
+    }
+}
+
+impl Rusty for LvEnum {
+    type Parent = LvWidget;
+
+    fn code(&self, _parent: &Self::Parent) -> WrapperResult<TokenStream> {
+        let name = self.ident();
+        let mut variants: Vec<TokenStream> = self
+            .variants
+            .iter()
+            .map(|v| {
+                let variant = format_ident!("{}", v.name);
+                let const_name = format_ident!("{}", v.const_name);
+                quote!(#variant = lvgl_sys::#const_name as u8)
+            })
+            .collect();
+        // Styles can be added to all the parts of a widget at once
+        if self.is_part() && !self.variants.iter().any(|v| v.value == LV_OBJ_PART_ALL) {
+            variants.push(quote!(All = lvgl_sys::LV_OBJ_PART_ALL as u8));
+        }
+        Ok(quote! {
+            #[derive(Debug, Copy, Clone, PartialEq)]
+            #[repr(u8)]
+            pub enum #name {
+                #(#variants),*
+            }
+
+            impl From<#name> for u8 {
+                fn from(value: #name) -> Self {
+                    value as u8
+                }
+            }
+        })
+    }
+}
+
+/// A non-widget LVGL module, e.g. the `lv_group_*` functions wrapped by `Group`.
+#[derive(Clone)]
+pub struct LvModule {
+    name: String,
+    native_type: String,
+    rust_type: String,
+    generated: bool,
+    functions: Vec<LvFunc>,
+}
+
+impl LvModule {
+    fn from_static(module: &(&str, &str, &str, bool)) -> Self {
+        let (name, native_type, rust_type, generated) = *module;
+        Self {
+            name: name.to_string(),
+            native_type: native_type.to_string(),
+            rust_type: rust_type.to_string(),
+            generated,
+            functions: Vec::new(),
+        }
+    }
+
+    pub fn ident(&self) -> Ident {
+        format_ident!("{}", self.rust_type)
+    }
+}
+
+impl Rusty for LvModule {
+    type Parent = ();
+
+    fn code(&self, _parent: &Self::Parent) -> WrapperResult<TokenStream> {
+        let name = self.ident();
+        let native_type = format_ident!("{}", self.native_type);
+        let definition = if self.generated {
+            quote!(define_module!(#name, lvgl_sys::#native_type);)
+        } else {
+            quote!()
+        };
+        let functions: Vec<TokenStream> = self
+            .functions
+            .iter()
+            .flat_map(|f| f.module_code(self))
+            .collect();
+        Ok(quote! {
+            #definition
+
+            impl #name {
+                #(#functions)*
+            }
+        })
+    }
+}
+
 #[derive(Clone)]
 pub struct LvFunc {
     name: String,
     args: Vec<LvArg>,
     ret: Option<LvType>,
+    doc: LvDoc,
+    skip: bool,
 }
 
 impl LvFunc {
     pub fn new(name: String, args: Vec<LvArg>, ret: Option<LvType>) -> Self {
-        Self { name, args, ret }
+        Self {
+            name,
+            args,
+            ret,
+            doc: LvDoc::default(),
+            skip: false,
+        }
+    }
+
+    /// Apply the type mappings and skip list of the configuration to this function.
+    pub fn configure(&mut self, config: &Config) {
+        self.skip = config.skip.contains(&self.name);
+        for (native, rust) in &config.type_mappings {
+            for arg in &mut self.args {
+                arg.typ.map_type(native, rust);
+            }
+            if let Some(ret) = &mut self.ret {
+                ret.map_type(native, rust);
+            }
+        }
+    }
+
+    fn is_skipped(&self) -> bool {
+        self.skip || HAND_WRITTEN.contains(&self.name.as_str())
+    }
+
+    pub fn with_doc(mut self, doc: LvDoc) -> Self {
+        self.doc = doc;
+        self
     }
 
     pub fn is_method(&self) -> bool {
@@ -90,15 +475,65 @@ impl Rusty for LvFunc {
     type Parent = LvWidget;
 
     fn code(&self, parent: &Self::Parent) -> WrapperResult<TokenStream> {
+        if self.skip {
+            return Err(WrapperError::Skip);
+        }
         let templ = format!("{}{}_", LIB_PREFIX, parent.name.as_str());
         let new_name = self.name.replace(templ.as_str(), "");
         let func_name = format_ident!("{}", new_name);
         let original_func_name = format_ident!("{}", self.name.as_str());
 
-        // generate constructor
+        // generate constructors
         if new_name.as_str().eq("create") {
+            let (par, copy) = match self.args.as_slice() {
+                [par, copy] => (par.name.as_str(), copy.name.as_str()),
+                _ => return Err(WrapperError::Skip),
+            };
+            let new_doc = self.doc.code(&[(par, "parent")]);
+            let copy_doc = self.doc.code(&[(par, "parent"), (copy, "copy")]);
+            let screen = if SCREEN_WIDGETS.contains(&parent.name.as_str()) {
+                let screen_doc = self.doc.code(&[]);
+                quote! {
+                    #screen_doc
+                    /// The object is created as a screen, without a parent.
+                    pub fn new_screen() -> crate::LvResult<Self> {
+                        unsafe {
+                            let ptr = lvgl_sys::#original_func_name(core::ptr::null_mut(), core::ptr::null_mut());
+                            if let Some(raw) = core::ptr::NonNull::new(ptr) {
+                                let core = <crate::Obj as crate::Widget>::from_raw(raw);
+                                Ok(Self { core })
+                            } else {
+                                Err(crate::LvError::InvalidReference)
+                            }
+                        }
+                    }
+                }
+            } else {
+                quote!()
+            };
+            let new_copy = if HAND_WRITTEN_COPIES.contains(&parent.name.as_str()) {
+                quote!()
+            } else {
+                quote! {
+                    #copy_doc
+                    pub fn new_copy<C>(parent: &mut C, copy: &Self) -> crate::LvResult<Self>
+                    where
+                        C: crate::NativeObject,
+                    {
+                        unsafe {
+                            let ptr = lvgl_sys::#original_func_name(parent.raw()?.as_mut(), copy.raw()?.as_ptr());
+                            if let Some(raw) = core::ptr::NonNull::new(ptr) {
+                                let core = <crate::Obj as crate::Widget>::from_raw(raw);
+                                Ok(Self { core })
+                            } else {
+                                Err(crate::LvError::InvalidReference)
+                            }
+                        }
+                    }
+                }
+            };
             return Ok(quote! {
-
+                #new_doc
                 pub fn new<C>(parent: &mut C) -> crate::LvResult<Self>
                 where
                     C: crate::NativeObject,
@@ -114,103 +549,225 @@ impl Rusty for LvFunc {
                     }
                 }
 
+                #new_copy
+
+                #screen
             });
         }
 
-        // We don't deal with methods that return types yet
-        if self.ret.is_some() {
+        if WIDGET_METHODS.contains(&new_name.as_str()) {
             return Err(WrapperError::Skip);
         }
 
-        // Make sure all arguments can be generated, skip the first arg (self)!
-        for arg in self.args.iter().skip(1) {
-            arg.code(self)?;
+        // if first arg is `const`, then it should be immutable
+        let receiver = match self.args.first() {
+            Some(arg) if arg.get_type().is_const() => quote!(&self),
+            _ => quote!(&mut self),
+        };
+        self.method_code(
+            &func_name,
+            Some((receiver, quote!(self.core.raw()?.as_mut()))),
+            &parent.enums,
+            quote!(pub),
+        )
+    }
+}
+
+impl LvFunc {
+    /// The default method of the `Widget` trait wrapping an `lv_obj_set_*`/`lv_obj_get_*`
+    /// function, so every widget inherits it.
+    pub fn base_method_code(&self, base: &LvWidget) -> WrapperResult<TokenStream> {
+        let templ = format!("{}{}_", LIB_PREFIX, base.name.as_str());
+        let new_name = self.name.replace(templ.as_str(), "");
+        if !(new_name.starts_with("set_") || new_name.starts_with("get_"))
+            || WIDGET_METHODS.contains(&new_name.as_str())
+        {
+            return Err(WrapperError::Skip);
         }
+        let func_name = format_ident!("{}", new_name);
+        let receiver = match self.args.first() {
+            Some(arg) if arg.get_type().is_const() => quote!(&self),
+            _ => quote!(&mut self),
+        };
+        // The enums of the base object are not generated
+        self.method_code(
+            &func_name,
+            Some((receiver, quote!(self.raw()?.as_mut()))),
+            &[],
+            quote!(),
+        )
+    }
 
-        let args_decl = self
-            .args
-            .iter()
-            .enumerate()
-            .fold(quote!(), |args, (i, arg)| {
-                // if first arg is `const`, then it should be immutable
-                let next_arg = if i == 0 {
-                    if arg.get_type().is_const() {
-                        quote!(&self)
-                    } else {
-                        quote!(&mut self)
-                    }
-                } else {
-                    arg.code(self).unwrap()
-                };
-                if args.is_empty() {
-                    quote! {
-                        #next_arg
-                    }
+    /// The wrapper of a module function, a method if its first argument is the module type.
+    pub fn module_code(&self, module: &LvModule) -> WrapperResult<TokenStream> {
+        let templ = format!("{}{}_", LIB_PREFIX, module.name.as_str());
+        let new_name = self.name.replace(templ.as_str(), "");
+        if MODULE_DESTRUCTORS.contains(&new_name.as_str()) {
+            return self.destructor_code(module);
+        }
+        let func_name = format_ident!("{}", new_name);
+        let receiver = match self.args.first() {
+            Some(arg) if arg.get_type().points_to(module.native_type.as_str()) => {
+                if arg.get_type().is_const() {
+                    Some((quote!(&self), quote!(self.raw())))
                 } else {
-                    quote! {
-                        #args, #next_arg
-                    }
+                    Some((quote!(&mut self), quote!(self.raw())))
                 }
-            });
+            }
+            _ => None,
+        };
+        self.method_code(&func_name, receiver, &[], quote!(pub))
+    }
 
-        let args_processing = self
-            .args
-            .iter()
-            .enumerate()
-            .fold(quote!(), |args, (i, arg)| {
-                // if first arg is `const`, then it should be immutable
-                let next_arg = if i == 0 {
-                    quote!()
-                } else {
-                    let var = arg.get_processing();
-                    quote!(#var)
-                };
-                if args.is_empty() {
-                    quote! {
-                        #next_arg
+    /// The `delete` method of a module handle, releasing the object when its only argument is
+    /// the receiver.
+    fn destructor_code(&self, module: &LvModule) -> WrapperResult<TokenStream> {
+        if self.is_skipped() {
+            return Err(WrapperError::Skip);
+        }
+        match self.args.as_slice() {
+            [arg]
+                if arg.get_type().points_to(module.native_type.as_str())
+                    && !arg.get_type().is_const() =>
+            {
+                let original_func_name = format_ident!("{}", self.name.as_str());
+                Ok(quote! {
+                    /// Release the object. Other handles to the same object, e.g. returned by a
+                    /// getter, must not be used afterwards.
+                    pub fn delete(self) {
+                        unsafe {
+                            lvgl_sys::#original_func_name(self.raw());
+                        }
                     }
+                })
+            }
+            _ => Err(WrapperError::Skip),
+        }
+    }
+
+    /// The wrapper of a function, as a method when there is a `receiver` (its declaration and
+    /// the native value passed as first argument), or as an associated function otherwise.
+    fn method_code(
+        &self,
+        func_name: &Ident,
+        receiver: Option<(TokenStream, TokenStream)>,
+        enums: &[LvEnum],
+        vis: TokenStream,
+    ) -> WrapperResult<TokenStream> {
+        if self.is_skipped() {
+            return Err(WrapperError::Skip);
+        }
+        let original_func_name = format_ident!("{}", self.name.as_str());
+        let ret = match &self.ret {
+            Some(ret) => Some(ret.return_code()?),
+            None => None,
+        };
+        let (receiver_decl, receiver_call) = match receiver {
+            Some((decl, call)) => (Some(decl), Some(call)),
+            None => (None, None),
+        };
+        let args = &self.args[usize::from(receiver_call.is_some())..];
+        let slices = self.slice_args(args);
+        let is_len = |i: usize| slices.iter().any(|&(_, len)| len == i);
+
+        // Make sure all arguments can be generated
+        let mut args_decl: Vec<TokenStream> = receiver_decl.into_iter().collect();
+        let mut args_processing: Vec<TokenStream> = Vec::new();
+        let mut args_call: Vec<TokenStream> = receiver_call.into_iter().collect();
+        for (i, arg) in args.iter().enumerate() {
+            let ident = arg.get_name_ident();
+            if let Some(&(ptr, _)) = slices.iter().find(|&&(_, len)| len == i) {
+                let slice = args[ptr].get_name_ident();
+                args_processing.push(quote! {
+                    let #ident = core::convert::TryInto::try_into(#slice.len())
+                        .map_err(|_| crate::LvError::InvalidReference)?;
+                });
+                args_call.push(quote!(#ident));
+            } else if slices.iter().any(|&(ptr, _)| ptr == i) {
+                let (decl, usage) = arg.slice_code(self.is_static_arg(arg))?;
+                args_decl.push(decl);
+                args_call.push(usage);
+            } else if arg.get_type().is_str() && self.is_static_arg(arg) {
+                args_decl.push(quote!(#ident: &'static cstr_core::CStr));
+                args_call.push(arg.get_value_usage());
+            } else {
+                args_decl.push(self.arg_code(arg, enums)?);
+                args_processing.push(arg.get_processing());
+                if enum_for(enums, arg.get_type()).is_some() {
+                    args_call.push(quote!(#ident.into()));
                 } else {
-                    quote! {
-                        #args
-                        #next_arg
-                    }
+                    args_call.push(arg.get_value_usage());
                 }
-            });
+            }
+        }
 
-        let args_call = self
-            .args
+        let arg_names: Vec<(&str, &str)> = args
             .iter()
             .enumerate()
-            .fold(quote!(), |args, (i, arg)| {
-                // if first arg is `const`, then it should be immutable
-                let next_arg = if i == 0 {
-                    quote!(self.core.raw()?.as_mut())
-                } else {
-                    let var = arg.get_value_usage();
-                    quote!(#var)
-                };
-                if args.is_empty() {
-                    quote! {
-                        #next_arg
-                    }
-                } else {
-                    quote! {
-                        #args, #next_arg
+            .filter(|&(i, _)| !is_len(i))
+            .map(|(_, arg)| (arg.name.as_str(), arg.name.as_str()))
+            .collect();
+        let doc = self.doc.code(&arg_names);
+
+        if let Some((ret_type, ret_value)) = ret {
+            return Ok(quote! {
+                #doc
+                #vis fn #func_name(#(#args_decl),*) -> crate::LvResult<#ret_type> {
+                    #(#args_processing)*
+                    unsafe {
+                        let value = lvgl_sys::#original_func_name(#(#args_call),*);
+                        #ret_value
                     }
                 }
             });
+        }
 
-        // TODO: Handle methods that return types
         Ok(quote! {
-            pub fn #func_name(#args_decl) -> crate::LvResult<()> {
-                #args_processing
+            #doc
+            #vis fn #func_name(#(#args_decl),*) -> crate::LvResult<()> {
+                #(#args_processing)*
                 unsafe {
-                    lvgl_sys::#original_func_name(#args_call);
+                    lvgl_sys::#original_func_name(#(#args_call),*);
                 }
                 Ok(())
             }
         })
     }
+
+    /// Array arguments passed with their length, as pairs of the indices of the pointer and of
+    /// the length argument. The length follows the pointer, or precedes it.
+    fn slice_args(&self, args: &[LvArg]) -> Vec<(usize, usize)> {
+        let mut slices: Vec<(usize, usize)> = Vec::new();
+        for (i, arg) in args.iter().enumerate() {
+            if arg.get_type().slice_element().is_none() {
+                continue;
+            }
+            let taken = |j: usize| slices.iter().any(|&(ptr, len)| ptr == j || len == j);
+            let len = [i + 1, i.wrapping_sub(1)]
+                .iter()
+                .cloned()
+                .find(|&j| matches!(args.get(j), Some(arg) if arg.is_len()) && !taken(j));
+            if let Some(len) = len {
+                slices.push((i, len));
+            }
+        }
+        slices
+    }
+
+    fn is_static_arg(&self, arg: &LvArg) -> bool {
+        STATIC_ARGS.contains(&(self.name.as_str(), arg.name.as_str()))
+    }
+
+    fn arg_code(&self, arg: &LvArg, enums: &[LvEnum]) -> WrapperResult<TokenStream> {
+        match enum_for(enums, arg.get_type()) {
+            Some(widget_enum) => {
+                let name = arg.get_name_ident();
+                let typ = widget_enum.ident();
+                Ok(quote!(#name: #typ))
+            }
+            None => arg.code(self),
+        }
+    }
 }
 
 impl From<ForeignItemFn> for LvFunc {
@@ -219,6 +776,7 @@ impl From<ForeignItemFn> for LvFunc {
             ReturnType::Default => None,
             ReturnType::Type(_, typ) => Some(typ.into()),
         };
+        let doc = LvDoc::from(ffi.attrs.as_slice());
         Self::new(
             ffi.sig.ident.to_string(),
             ffi.sig
@@ -236,6 +794,95 @@ impl From<ForeignItemFn> for LvFunc {
                 .collect::<Vec<LvArg>>(),
             ret,
         )
+        .with_doc(doc)
+    }
+}
+
+/// The Doxygen comment of an LVGL function, as found in its header.
+#[derive(Clone, Default)]
+pub struct LvDoc {
+    description: Vec<String>,
+    params: Vec<(String, String)>,
+    ret: Option<String>,
+}
+
+impl LvDoc {
+    pub fn parse(comment: &str) -> Self {
+        let mut doc = Self::default();
+        let mut in_tags = false;
+        for line in comment.lines().map(str::trim) {
+            if let Some(param) = line.strip_prefix("@param") {
+                let param = param.trim();
+                let (name, text) = param.split_at(param.find(' ').unwrap_or(param.len()));
+                doc.params.push((name.to_string(), text.trim().to_string()));
+                in_tags = true;
+            } else if let Some(ret) = line.strip_prefix("@return") {
+                doc.ret = Some(ret.trim().to_string());
+                in_tags = true;
+            } else if line.is_empty() {
+                continue;
+            } else if in_tags {
+                // Continuation of the previous tag
+                let last = match (&mut doc.ret, doc.params.last_mut()) {
+                    (Some(ret), _) => ret,
+                    (None, Some((_, text))) => text,
+                    (None, None) => continue,
+                };
+                last.push(' ');
+                last.push_str(line);
+            } else {
+                doc.description.push(line.to_string());
+            }
+        }
+        doc
+    }
+
+    /// The documentation of a wrapper, `params` maps the names of the documented arguments to
+    /// the names used by the wrapper, arguments not in `params` are left out.
+    pub fn code(&self, params: &[(&str, &str)]) -> TokenStream {
+        let mut lines: Vec<String> = self
+            .description
+            .iter()
+            .map(|line| format!(" {}", line))
+            .collect();
+        let params: Vec<String> = self
+            .params
+            .iter()
+            .filter_map(|(c_name, text)| {
+                params
+                    .iter()
+                    .find(|(name, _)| name == c_name)
+                    .map(|(_, name)| format!(" * `{}`: {}", name, text))
+            })
+            .collect();
+        if !params.is_empty() {
+            lines.push(String::new());
+            lines.extend(params);
+        }
+        if let Some(ret) = &self.ret {
+            lines.push(String::new());
+            lines.push(format!(" Returns {}", ret));
+        }
+        quote! {
+            #(#[doc = #lines])*
+        }
+    }
+}
+
+impl From<&[syn::Attribute]> for LvDoc {
+    fn from(attrs: &[syn::Attribute]) -> Self {
+        let comment: Vec<String> = attrs
+            .iter()
+            .filter(|attr| attr.path.is_ident("doc"))
+            .filter_map(|attr| match attr.parse_meta() {
+                Ok(syn::Meta::NameValue(syn::MetaNameValue {
+                    lit: syn::Lit::Str(text),
+                    ..
+                })) => Some(text.value()),
+                _ => None,
+            })
+            .collect();
+        Self::parse(comment.join("\n").as_str())
     }
 }
 
@@ -276,6 +923,18 @@ impl LvArg {
             quote! {
                 #ident.as_ptr()
             }
+        } else if self.typ.is_obj_ptr() {
+            quote! {
+                #ident.raw()?.as_ptr()
+            }
+        } else if self.typ.module_type().is_some() {
+            quote! {
+                #ident.raw()
+            }
+        } else if self.typ.wrapper_type().is_some() {
+            quote! {
+                #ident.into()
+            }
         } else {
             quote! {
                 #ident
@@ -286,6 +945,51 @@ impl LvArg {
     pub fn get_type(&self) -> &LvType {
         &self.typ
     }
+
+    /// Whether this is an integer argument giving the length of an array argument.
+    pub fn is_len(&self) -> bool {
+        let integer = match TYPE_MAPPINGS.get(self.typ.literal_name.as_str()) {
+            Some(typ) => *typ != "bool" && *typ != "_",
+            None => false,
+        };
+        integer
+            && SLICE_LEN_SUFFIXES
+                .iter()
+                .any(|suffix| self.name == *suffix || self.name.ends_with(&format!("_{}", suffix)))
+    }
+
+    /// The declaration of an array argument as a slice, and its usage as a native pointer.
+    /// A `'static` slice is required when LVGL keeps the pointer, otherwise LVGL copies it.
+    pub fn slice_code(&self, is_static: bool) -> WrapperResult<(TokenStream, TokenStream)> {
+        let ident = self.get_name_ident();
+        let (element, native) = self
+            .typ
+            .slice_element()
+            .ok_or_else(|| self.typ.unmapped())?;
+        let lifetime = if is_static { quote!('static) } else { quote!() };
+        let (decl, ptr, native_ptr) = if self.typ.is_const() {
+            (
+                quote!(&#lifetime [#element]),
+                quote!(#ident.as_ptr()),
+                quote!(*const lvgl_sys::#native),
+            )
+        } else {
+            (
+                quote!(&#lifetime mut [#element]),
+                quote!(#ident.as_mut_ptr()),
+                quote!(*mut lvgl_sys::#native),
+            )
+        };
+        let usage = if SLICE_ELEMENT_MAPPINGS
+            .iter()
+            .any(|(name, _)| native == *name)
+        {
+            quote!(#ptr as #native_ptr)
+        } else {
+            ptr
+        };
+        Ok((quote!(#ident: #decl), usage))
+    }
 }
 
 impl Rusty for LvArg {
@@ -303,37 +1007,162 @@ impl Rusty for LvArg {
 #[derive(Clone)]
 pub struct LvType {
     literal_name: String,
-    r_type: Option<Box<syn::Type>>,
 }
 
 impl LvType {
     pub fn new(literal_name: String) -> Self {
-        Self {
-            literal_name,
-            r_type: None,
-        }
+        Self { literal_name }
     }
 
     pub fn from(r_type: Box<syn::Type>) -> Self {
         Self {
             literal_name: r_type.to_token_stream().to_string(),
-            r_type: Some(r_type),
         }
     }
 
     pub fn is_const(&self) -> bool {
-        self.literal_name.starts_with("const ")
+        self.compact_name().starts_with("*const")
     }
 
     pub fn is_str(&self) -> bool {
         self.literal_name.ends_with("* const cty :: c_char")
     }
+
+    pub fn is_obj_ptr(&self) -> bool {
+        self.points_to("lv_obj_t")
+    }
+
+    pub fn points_to(&self, native_type: &str) -> bool {
+        let name = self.compact_name();
+        name == format!("*const{}", native_type) || name == format!("*mut{}", native_type)
+    }
+
+    /// The generated module type of a pointer to a module object, e.g. `Group`.
+    pub fn module_type(&self) -> Option<syn::Path> {
+        MODULES
+            .iter()
+            .find(|(_, native_type, _, generated)| *generated && self.points_to(native_type))
+            .map(|(_, _, rust_type, _)| syn::parse_str(&format!("crate::{}", rust_type)).unwrap())
+    }
+
+    /// The Rust type of the elements of an array argument, and the native element type.
+    pub fn slice_element(&self) -> Option<(syn::Path, Ident)> {
+        let name = self.compact_name();
+        let native = name
+            .strip_prefix("*const")
+            .or_else(|| name.strip_prefix("*mut"))?;
+        let element = SLICE_ELEMENT_MAPPINGS
+            .iter()
+            .find(|(name, _)| *name == native)
+            .map(|(_, path)| *path)
+            .or_else(|| match TYPE_MAPPINGS.get(native) {
+                Some(typ) if *typ != "_" => Some(*typ),
+                _ => None,
+            })?;
+        Some((
+            syn::parse_str(element).unwrap(),
+            format_ident!("{}", native),
+        ))
+    }
+
+    /// The safe Rust type used in place of this native type, if there is one.
+    pub fn wrapper_type(&self) -> Option<syn::Path> {
+        WRAPPER_TYPE_MAPPINGS
+            .get(self.literal_name.as_str())
+            .map(|path| syn::parse_str(path).unwrap())
+    }
+
+    /// The Rust type returned by a wrapper function, and the expression converting the native
+    /// `value` into it. Strings are borrowed from the object and pointers may be null.
+    pub fn return_code(&self) -> WrapperResult<(TokenStream, TokenStream)> {
+        let name = self.compact_name();
+        if name == "*constcty::c_char" || name == "*mutcty::c_char" {
+            Ok((
+                quote!(&cstr_core::CStr),
+                quote! {
+                    if value.is_null() {
+                        Err(crate::LvError::InvalidReference)
+                    } else {
+                        Ok(cstr_core::CStr::from_ptr(value))
+                    }
+                },
+            ))
+        } else if self.literal_name == "lv_color_t" {
+            Ok((
+                quote!(crate::Color),
+                quote!(Ok(crate::Color::from_raw(value))),
+            ))
+        } else if self.is_obj_ptr() {
+            let value = if self.is_const() {
+                quote!(value as *mut lvgl_sys::lv_obj_t)
+            } else {
+                quote!(value)
+            };
+            Ok((
+                quote!(Option<crate::Obj>),
+                quote! {
+                    Ok(core::ptr::NonNull::new(#value)
+                        .map(|raw| <crate::Obj as crate::Widget>::from_raw(raw)))
+                },
+            ))
+        } else if let Some(path) = self.module_type() {
+            let value = if self.is_const() {
+                quote!(value as *mut _)
+            } else {
+                quote!(value)
+            };
+            Ok((
+                quote!(Option<#path>),
+                quote! {
+                    Ok(core::ptr::NonNull::new(#value).map(|raw| #path::from_raw(raw)))
+                },
+            ))
+        } else if name.starts_with('*') {
+            // Other pointers need their own safe type
+            Err(self.unmapped())
+        } else {
+            match TYPE_MAPPINGS.get(self.literal_name.as_str()) {
+                Some(typ) => {
+                    let ident = format_ident!("{}", typ);
+                    Ok((quote!(#ident), quote!(Ok(value))))
+                }
+                None => Err(self.unmapped()),
+            }
+        }
+    }
+
+    /// Replace the `native` type by the `rust` type, also behind pointers.
+    pub fn map_type(&mut self, native: &str, rust: &str) {
+        let tokens: Vec<&str> = self
+            .literal_name
+            .split_whitespace()
+            .map(|token| if token == native { rust } else { token })
+            .collect();
+        self.literal_name = tokens.join(" ");
+    }
+
+    fn compact_name(&self) -> String {
+        self.literal_name.replace(' ', "")
+    }
+
+    fn unmapped(&self) -> WrapperError {
+        WrapperError::UnmappedType(self.literal_name.clone())
+    }
 }
 
 impl Rusty for LvType {
     type Parent = LvArg;
 
     fn code(&self, _parent: &Self::Parent) -> WrapperResult<TokenStream> {
+        if self.is_obj_ptr() {
+            return Ok(quote!(&impl crate::NativeObject));
+        }
+        if let Some(path) = self.module_type() {
+            return Ok(quote!(&#path));
+        }
+        if let Some(path) = self.wrapper_type() {
+            return Ok(quote!(#path));
+        }
         match TYPE_MAPPINGS.get(self.literal_name.as_str()) {
             Some(name) => {
                 let val = if self.is_str() {
@@ -346,7 +1175,7 @@ impl Rusty for LvType {
                     #val
                 })
             }
-            None => Err(WrapperError::Skip),
+            None => Err(self.unmapped()),
         }
     }
 }
@@ -357,35 +1186,485 @@ impl From<Box<syn::Type>> for LvType {
     }
 }
 
-pub struct CodeGen {
-    functions: Vec<LvFunc>,
-    widgets: Vec<LvWidget>,
+/// The `Style` type, with accessors for all the style properties defined by LVGL.
+#[derive(Clone)]
+pub struct LvStyle {
+    props: Vec<LvStyleProp>,
+    ids: StyleIds,
 }
 
-impl CodeGen {
-    pub fn from(code: &str) -> CGResult<Self> {
-        let functions = Self::load_func_defs(code)?;
-        let widgets = Self::extract_widgets(&functions)?;
-        Ok(Self { functions, widgets })
-    }
-
-    pub fn get_widgets(&self) -> &Vec<LvWidget> {
-        &self.widgets
-    }
-
-    fn extract_widgets(functions: &[LvFunc]) -> CGResult<Vec<LvWidget>> {
-        let widget_names = Self::get_widget_names(functions);
+impl Rusty for LvStyle {
+    type Parent = ();
 
-        let widgets = functions.iter().fold(HashMap::new(), |mut ws, f| {
+    fn code(&self, _parent: &Self::Parent) -> WrapperResult<TokenStream> {
+        // Properties without accessors can't be used either
+        let flags: Vec<TokenStream> = self
+            .props
+            .iter()
+            .filter(|p| p.is_supported(&self.ids))
+            .map(|p| {
+                let flag_name = format_ident!("{}", p.name.to_uppercase());
+                let const_name = p.const_ident();
+                quote! {
+                    const #flag_name = lvgl_sys::#const_name;
+                }
+            })
+            .collect();
+        let accessors: Vec<TokenStream> = self.props.iter().flat_map(|p| p.code(self)).collect();
+        Ok(quote! {
+            bitflags! {
+                pub struct StyleProp: u32 {
+                    #(#flags)*
+                }
+            }
+
+            impl Style {
+                #(#accessors)*
+            }
+        })
+    }
+}
+
+/// The kind of value a style property holds, as encoded by LVGL in the property ID.
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub enum StyleValueType {
+    Int,
+    Color,
+    Opa,
+    Ptr,
+}
+
+/// The first ID of each type of style property value, the `LV_STYLE_ID_*` constants. The type
+/// of a property is encoded in the low nibble of its ID.
+#[derive(Debug, Default, Copy, Clone, PartialEq)]
+pub struct StyleIds {
+    pub value: u32,
+    pub color: u32,
+    pub opa: u32,
+    pub ptr: u32,
+}
+
+impl StyleIds {
+    pub fn value_type(&self, prop: u32) -> StyleValueType {
+        match prop & 0xF {
+            id if id >= self.ptr => StyleValueType::Ptr,
+            id if id >= self.opa => StyleValueType::Opa,
+            id if id >= self.color => StyleValueType::Color,
+            _ => StyleValueType::Int,
+        }
+    }
+}
+
+#[derive(Clone)]
+pub struct LvStyleProp {
+    name: String,
+    value: u32,
+}
+
+impl LvStyleProp {
+    pub fn new(name: String, value: u32) -> Self {
+        Self { name, value }
+    }
+
+    pub fn value_type(&self, ids: &StyleIds) -> StyleValueType {
+        ids.value_type(self.value)
+    }
+
+    /// Whether the property has accessors, pointers need a safe Rust type.
+    pub fn is_supported(&self, ids: &StyleIds) -> bool {
+        self.value_type(ids) != StyleValueType::Ptr
+            || STYLE_PTR_MAPPINGS.contains_key(self.const_ident().to_string().as_str())
+    }
+
+    fn const_ident(&self) -> Ident {
+        format_ident!("{}{}", STYLE_PROP_PREFIX, self.name.to_uppercase())
+    }
+}
+
+impl Rusty for LvStyleProp {
+    type Parent = LvStyle;
+
+    fn code(&self, parent: &Self::Parent) -> WrapperResult<TokenStream> {
+        let const_name = self.const_ident();
+        let setter = format_ident!("set_{}", self.name);
+        let getter = format_ident!("get_{}", self.name);
+        let (kind, value_type) = match self.value_type(&parent.ids) {
+            StyleValueType::Int => ("int", quote!(i16)),
+            StyleValueType::Color => ("color", quote!(crate::Color)),
+            StyleValueType::Opa => ("opa", quote!(Opacity)),
+            StyleValueType::Ptr => match STYLE_PTR_MAPPINGS.get(const_name.to_string().as_str()) {
+                Some(&"str") => ("str", quote!(&cstr_core::CStr)),
+                Some(&"font") => ("font", quote!(crate::Font)),
+                Some(&"image") => ("image", quote!(crate::ImageSource)),
+                Some(&"anim_path") => ("anim_path", quote!(&'static crate::AnimPath)),
+                _ => return Err(WrapperError::Skip),
+            },
+        };
+        let set_value = format_ident!("set_{}", kind);
+        let get_value = format_ident!("get_{}", kind);
+
+        // Copying the string into the style memory can fail
+        let setter = if kind == "str" {
+            quote! {
+                pub fn #setter(&mut self, state: crate::State, value: #value_type) -> crate::LvResult<()> {
+                    self.#set_value(lvgl_sys::#const_name, state, value)
+                }
+            }
+        } else {
+            quote! {
+                pub fn #setter(&mut self, state: crate::State, value: #value_type) {
+                    self.#set_value(lvgl_sys::#const_name, state, value);
+                }
+            }
+        };
+
+        // Images and animation paths can not be safely read back from the raw pointer
+        let getter = match kind {
+            "image" | "anim_path" => quote!(),
+            _ => quote! {
+                pub fn #getter(&self, state: crate::State) -> Option<#value_type> {
+                    self.#get_value(lvgl_sys::#const_name, state)
+                }
+            },
+        };
+
+        Ok(quote! {
+            #setter
+            #getter
+        })
+    }
+}
+
+impl From<ItemConst> for LvStyleProp {
+    fn from(item: ItemConst) -> Self {
+        let name = item.ident.to_string();
+        Self::new(
+            name.trim_start_matches(STYLE_PROP_PREFIX).to_lowercase(),
+            const_value(&item),
+        )
+    }
+}
+
+fn const_value(item: &ItemConst) -> u32 {
+    match &*item.expr {
+        syn::Expr::Lit(syn::ExprLit {
+            lit: syn::Lit::Int(lit),
+            ..
+        }) => lit.base10_parse::<u32>().unwrap_or_default(),
+        _ => 0,
+    }
+}
+
+/// Which LVGL functions have a Rust wrapper, and why the others do not.
+#[derive(Debug, Default)]
+pub struct CoverageReport {
+    pub wrapped: Vec<String>,
+    pub skipped: Vec<(String, WrapperError)>,
+}
+
+impl CoverageReport {
+    /// The native types without a Rust mapping, with the number of functions using each one.
+    pub fn unmapped_types(&self) -> BTreeMap<&str, usize> {
+        let mut types = BTreeMap::new();
+        for (_, reason) in &self.skipped {
+            if let WrapperError::UnmappedType(typ) = reason {
+                *types.entry(typ.as_str()).or_insert(0) += 1;
+            }
+        }
+        types
+    }
+
+    pub fn reason(&self, function: &str) -> Option<&WrapperError> {
+        self.skipped
+            .iter()
+            .find(|(name, _)| name == function)
+            .map(|(_, reason)| reason)
+    }
+}
+
+impl fmt::Display for CoverageReport {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let total = self.wrapped.len() + self.skipped.len();
+        writeln!(
+            f,
+            "{} of {} LVGL functions wrapped ({}%)",
+            self.wrapped.len(),
+            total,
+            self.wrapped.len() * 100 / total.max(1)
+        )?;
+        writeln!(f, "\nUnmapped types:")?;
+        let mut unmapped: Vec<(&str, usize)> = self.unmapped_types().into_iter().collect();
+        unmapped.sort_by_key(|&(_, count)| core::cmp::Reverse(count));
+        for (typ, count) in unmapped {
+            writeln!(f, "  {}: {} functions", typ, count)?;
+        }
+        writeln!(f, "\nSkipped functions:")?;
+        for (name, reason) in &self.skipped {
+            writeln!(f, "  {}: {}", name, reason)?;
+        }
+        writeln!(f, "\nWrapped functions:")?;
+        for name in &self.wrapped {
+            writeln!(f, "  {}", name)?;
+        }
+        Ok(())
+    }
+}
+
+/// Settings for another LVGL version or configuration, on top of the built-in mappings.
+#[derive(Debug, Clone, Default)]
+#[cfg_attr(feature = "cli", derive(serde::Deserialize))]
+#[cfg_attr(feature = "cli", serde(default))]
+pub struct Config {
+    /// Native types replaced by another type, e.g. `lv_coord_t` by `i32` when LVGL is built
+    /// with `LV_USE_LARGE_COORD`.
+    pub type_mappings: HashMap<String, String>,
+    /// Functions not to wrap.
+    pub skip: Vec<String>,
+}
+
+pub struct CodeGen {
+    functions: Vec<LvFunc>,
+    widgets: Vec<LvWidget>,
+    modules: Vec<LvModule>,
+    style: LvStyle,
+}
+
+impl CodeGen {
+    pub fn from(code: &str) -> CGResult<Self> {
+        Self::with_config(code, &Config::default())
+    }
+
+    pub fn with_config(code: &str, config: &Config) -> CGResult<Self> {
+        let mut functions = Self::load_func_defs(code)?;
+        for func in &mut functions {
+            func.configure(config);
+        }
+        let mut widgets = Self::extract_widgets(&functions)?;
+        let widget_names = Self::get_widget_names(&functions);
+        for (widget_name, widget_enum) in Self::load_enums(code, &widget_names)? {
+            if let Some(widget) = widgets.iter_mut().find(|w| w.name == widget_name) {
+                widget.enums.push(widget_enum);
+            }
+        }
+        let props = Self::load_style_props(code)?;
+        // The types of the properties are only needed when there are some
+        let ids = if props.is_empty() {
+            StyleIds::default()
+        } else {
+            Self::load_style_ids(code)?
+        };
+        let style = LvStyle { props, ids };
+        let modules = Self::extract_modules(&functions);
+        Ok(Self {
+            functions,
+            widgets,
+            modules,
+            style,
+        })
+    }
+
+    pub fn get_widgets(&self) -> &Vec<LvWidget> {
+        &self.widgets
+    }
+
+    pub fn get_style(&self) -> &LvStyle {
+        &self.style
+    }
+
+    pub fn get_modules(&self) -> &Vec<LvModule> {
+        &self.modules
+    }
+
+    /// The generic object, base of all the widgets.
+    pub fn get_base_widget(&self) -> Option<&LvWidget> {
+        self.widgets.iter().find(|w| w.name == "obj")
+    }
+
+    /// Write the generated sources and the coverage report in `out_dir`, returns the paths of
+    /// the Rust sources.
+    pub fn write_files(&self, out_dir: &Path) -> io::Result<Vec<PathBuf>> {
+        let widgets_impl: Vec<TokenStream> =
+            self.widgets.iter().flat_map(|w| w.code(&())).collect();
+        let modules_impl: Vec<TokenStream> =
+            self.modules.iter().flat_map(|m| m.code(&())).collect();
+        let style_impl = self
+            .style
+            .code(&())
+            .map_err(|e| io::Error::other(e.to_string()))?;
+        let sources = [
+            ("generated.rs", quote!(#(#widgets_impl)*)),
+            ("generated_style.rs", style_impl),
+            ("generated_modules.rs", quote!(#(#modules_impl)*)),
+            (
+                "generated_obj.rs",
+                self.get_base_widget()
+                    .map(LvWidget::base_methods_code)
+                    .unwrap_or_default(),
+            ),
+        ];
+
+        let mut paths = Vec::new();
+        for (name, code) in sources.iter() {
+            let path = out_dir.join(name);
+            let mut file = File::create(&path)?;
+            writeln!(
+                file,
+                "/* automatically generated by lvgl-codegen */\n{}",
+                code
+            )?;
+            paths.push(path);
+        }
+
+        // Functions that could not be wrapped, to find out what is missing in the Rust API
+        let mut file = File::create(out_dir.join("coverage.txt"))?;
+        write!(file, "{}", self.coverage())?;
+        Ok(paths)
+    }
+
+    /// Try to wrap every `lv_*` function and report the ones that could not be wrapped.
+    pub fn coverage(&self) -> CoverageReport {
+        let mut report = CoverageReport::default();
+        for func in &self.functions {
+            let widget = self
+                .widgets
+                .iter()
+                .find(|w| w.methods.iter().any(|m| m.name == func.name));
+            let module = self
+                .modules
+                .iter()
+                .find(|m| m.functions.iter().any(|f| f.name == func.name));
+            let result = match (widget, module) {
+                (Some(widget), _) if widget.name == "obj" => {
+                    func.base_method_code(widget).map(|_| ())
+                }
+                (Some(widget), _) => func.code(widget).map(|_| ()),
+                (None, Some(module)) => func.module_code(module).map(|_| ()),
+                (None, None) => Err(WrapperError::NotAWidgetMethod),
+            };
+            match result {
+                Ok(()) => report.wrapped.push(func.name.clone()),
+                Err(reason) => report.skipped.push((func.name.clone(), reason)),
+            }
+        }
+        report
+    }
+
+    /// Style properties are the `LV_STYLE_*` constants of an anonymous enum in LVGL, which
+    /// bindgen declares with a `_bindgen_ty_*` type. The other `LV_STYLE_*` constants are
+    /// `#define`s and have a plain integer type.
+    pub fn load_style_props(bindgen_code: &str) -> CGResult<Vec<LvStyleProp>> {
+        let props = Self::load_enum_consts(bindgen_code)?
+            .into_iter()
+            .filter(|c| c.ident.to_string().starts_with(STYLE_PROP_PREFIX))
+            .map(|c| c.into())
+            .collect::<Vec<LvStyleProp>>();
+        Ok(props)
+    }
+
+    /// The `LV_STYLE_ID_*` constants, which are `#define`s.
+    pub fn load_style_ids(bindgen_code: &str) -> CGResult<StyleIds> {
+        let ast: syn::File = syn::parse_str(bindgen_code)?;
+        let id = |name: &str| {
+            ast.items
+                .iter()
+                .find_map(|item| match item {
+                    Item::Const(c) if c.ident == name => Some(const_value(c)),
+                    _ => None,
+                })
+                .ok_or_else(|| format!("{} is not defined", name))
+        };
+        Ok(StyleIds {
+            value: id("LV_STYLE_ID_VALUE")?,
+            color: id("LV_STYLE_ID_COLOR")?,
+            opa: id("LV_STYLE_ID_OPA")?,
+            ptr: id("LV_STYLE_ID_PTR")?,
+        })
+    }
+
+    /// Widget enums are the `LV_<WIDGET>_<GROUP>_*` constants of an anonymous enum, for the
+    /// groups in `ENUM_GROUPS`. Returns each enum with the name of its widget.
+    pub fn load_enums(
+        bindgen_code: &str,
+        widget_names: &[String],
+    ) -> CGResult<Vec<(String, LvEnum)>> {
+        let mut enums: Vec<(String, LvEnum)> = Vec::new();
+        for c in Self::load_enum_consts(bindgen_code)? {
+            let const_name = c.ident.to_string();
+            for widget_name in widget_names {
+                for (group, rust_suffix, native_suffix) in ENUM_GROUPS.iter() {
+                    let prefix = format!("LV_{}_{}_", widget_name.to_uppercase(), group);
+                    if !const_name.starts_with(prefix.as_str()) {
+                        continue;
+                    }
+                    let variant = LvEnumVariant {
+                        name: to_pascal_case(&const_name[prefix.len()..].to_lowercase()),
+                        const_name: const_name.clone(),
+                        value: const_value(&c),
+                    };
+                    // Variants must be valid identifiers
+                    if syn::parse_str::<Ident>(variant.name.as_str()).is_err() {
+                        continue;
+                    }
+                    let native_type = format!("lv_{}_{}", widget_name, native_suffix);
+                    match enums.iter_mut().find(|(_, e)| e.native_type == native_type) {
+                        // Some constants are aliases, a Rust enum can only have one variant
+                        // for each value
+                        Some((_, e)) => {
+                            if !e.variants.iter().any(|v| v.value == variant.value) {
+                                e.variants.push(variant);
+                            }
+                        }
+                        None => enums.push((
+                            widget_name.clone(),
+                            LvEnum {
+                                name: format!("{}{}", to_pascal_case(widget_name), rust_suffix),
+                                native_type,
+                                variants: vec![variant],
+                            },
+                        )),
+                    }
+                }
+            }
+        }
+        Ok(enums)
+    }
+
+    /// Constants of anonymous enums, which bindgen declares with a `_bindgen_ty_*` type.
+    fn load_enum_consts(bindgen_code: &str) -> CGResult<Vec<ItemConst>> {
+        let ast: syn::File = syn::parse_str(bindgen_code)?;
+        let consts = ast
+            .items
+            .into_iter()
+            .filter_map(|e| {
+                if let Item::Const(c) = e {
+                    Some(c)
+                } else {
+                    None
+                }
+            })
+            .filter(|c| {
+                c.ty.to_token_stream()
+                    .to_string()
+                    .starts_with("_bindgen_ty_")
+            })
+            .collect::<Vec<ItemConst>>();
+        Ok(consts)
+    }
+
+    fn extract_widgets(functions: &[LvFunc]) -> CGResult<Vec<LvWidget>> {
+        let widget_names = Self::get_widget_names(functions);
+
+        let widgets = functions.iter().fold(HashMap::new(), |mut ws, f| {
             for widget_name in &widget_names {
                 if f.name
-                    .starts_with(format!("{}{}", LIB_PREFIX, widget_name).as_str())
+                    .starts_with(format!("{}{}_", LIB_PREFIX, widget_name).as_str())
                     && f.is_method()
                 {
                     ws.entry(widget_name.clone())
                         .or_insert_with(|| LvWidget {
                             name: widget_name.clone(),
                             methods: Vec::new(),
+                            enums: Vec::new(),
                         })
                         .methods
                         .push(f.clone())
@@ -397,6 +1676,22 @@ impl CodeGen {
         Ok(widgets.values().cloned().collect())
     }
 
+    fn extract_modules(functions: &[LvFunc]) -> Vec<LvModule> {
+        MODULES
+            .iter()
+            .map(|m| {
+                let mut module = LvModule::from_static(m);
+                let prefix = format!("{}{}_", LIB_PREFIX, module.name);
+                module.functions = functions
+                    .iter()
+                    .filter(|f| f.name.starts_with(prefix.as_str()))
+                    .cloned()
+                    .collect();
+                module
+            })
+            .collect()
+    }
+
     fn get_widget_names(functions: &[LvFunc]) -> Vec<String> {
         let reg = format!("^{}([^_]+)_create$", LIB_PREFIX);
         let create_func = Regex::new(reg.as_str()).unwrap();
@@ -451,7 +1746,10 @@ impl CodeGen {
 
 #[cfg(test)]
 mod test {
-    use crate::{CodeGen, LvArg, LvFunc, LvType, LvWidget, Rusty};
+    use crate::{
+        CodeGen, Config, LvArg, LvDoc, LvEnum, LvEnumVariant, LvFunc, LvStyle, LvStyleProp, LvType,
+        LvWidget, Rusty, StyleIds, StyleValueType, WrapperError,
+    };
     use quote::quote;
 
     #[test]
@@ -467,7 +1765,7 @@ mod test {
 
         let cg = CodeGen::load_func_defs(bindgen_code.to_string().as_str()).unwrap();
 
-        let ffn = cg.get(0).unwrap();
+        let ffn = cg.first().unwrap();
         assert_eq!(ffn.name, "lv_obj_get_screen");
         assert_eq!(ffn.args[0].name, "obj");
     }
@@ -536,6 +1834,7 @@ mod test {
         let arc_widget = LvWidget {
             name: "arc".to_string(),
             methods: vec![],
+            enums: vec![],
         };
 
         let code = arc_set_bg_end_angle.code(&arc_widget).unwrap();
@@ -563,15 +1862,18 @@ mod test {
         };
         let cg = CodeGen::load_func_defs(bindgen_code.to_string().as_str()).unwrap();
 
-        let label_set_text = cg.get(0).unwrap().clone();
+        let label_set_text = cg.first().unwrap().clone();
         let parent_widget = LvWidget {
             name: "label".to_string(),
             methods: vec![],
+            enums: vec![],
         };
 
         let code = label_set_text.code(&parent_widget).unwrap();
         let expected_code = quote! {
-
+            #[doc = " Set a new text for a label. Memory will be allocated to store the text by the label."]
+            #[doc = ""]
+            #[doc = " * `text`: '\\0' terminated character string. NULL to refresh with the current text."]
             pub fn set_text(&mut self, text: &cstr_core::CStr) -> crate::LvResult<()> {
                 unsafe {
                     lvgl_sys::lv_label_set_text(
@@ -587,11 +1889,279 @@ mod test {
         assert_eq!(code.to_string(), expected_code.to_string());
     }
 
+    #[test]
+    fn generate_method_wrapper_for_mapped_types() {
+        let bindgen_code = quote! {
+            extern "C" {
+                pub fn lv_bar_set_value(bar: *mut lv_obj_t, value: i16, anim: lv_anim_enable_t);
+                pub fn lv_cpicker_set_color(cpicker: *mut lv_obj_t, color: lv_color_t) -> bool;
+                pub fn lv_label_set_align(label: *mut lv_obj_t, align: lv_label_align_t);
+                pub fn lv_page_focus(page: *mut lv_obj_t, obj: *const lv_obj_t, anim_en: lv_anim_enable_t);
+            }
+        };
+        let cg = CodeGen::load_func_defs(bindgen_code.to_string().as_str()).unwrap();
+        let widget = |name: &str| LvWidget {
+            name: name.to_string(),
+            methods: vec![],
+            enums: vec![],
+        };
+
+        let code = cg[0].code(&widget("bar")).unwrap();
+        let expected_code = quote! {
+            pub fn set_value(&mut self, value: i16, anim: crate::Animation) -> crate::LvResult<()> {
+                unsafe {
+                    lvgl_sys::lv_bar_set_value(self.core.raw()?.as_mut(), value, anim.into());
+                }
+                Ok(())
+            }
+        };
+        assert_eq!(code.to_string(), expected_code.to_string());
+
+        let code = cg[1].code(&widget("cpicker")).unwrap();
+        let expected_code = quote! {
+            pub fn set_color(&mut self, color: crate::Color) -> crate::LvResult<bool> {
+                unsafe {
+                    let value = lvgl_sys::lv_cpicker_set_color(self.core.raw()?.as_mut(), color.into());
+                    Ok(value)
+                }
+            }
+        };
+        assert_eq!(code.to_string(), expected_code.to_string());
+
+        // Shadows `Widget::set_align`
+        assert!(cg[2].code(&widget("label")).is_err());
+
+        let code = cg[3].code(&widget("page")).unwrap();
+        let expected_code = quote! {
+            pub fn focus(&mut self, obj: &impl crate::NativeObject, anim_en: crate::Animation) -> crate::LvResult<()> {
+                unsafe {
+                    lvgl_sys::lv_page_focus(self.core.raw()?.as_mut(), obj.raw()?.as_ptr(), anim_en.into());
+                }
+                Ok(())
+            }
+        };
+        assert_eq!(code.to_string(), expected_code.to_string());
+    }
+
+    #[test]
+    fn generate_slice_and_static_arguments() {
+        let bindgen_code = quote! {
+            extern "C" {
+                pub fn lv_line_set_points(line: *mut lv_obj_t, point_a: *const lv_point_t, point_num: u16);
+                pub fn lv_gauge_set_needle_count(gauge: *mut lv_obj_t, needle_cnt: u8, colors: *const lv_color_t);
+                pub fn lv_label_set_text_static(label: *mut lv_obj_t, text: *const cty::c_char);
+                pub fn lv_calendar_set_highlighted_dates(calendar: *mut lv_obj_t, highlighted: *mut lv_calendar_date_t, date_num: u16);
+                pub fn lv_calendar_set_day_names(calendar: *mut lv_obj_t, day_names: *mut *const cty::c_char);
+                pub fn lv_tileview_set_valid_positions(tileview: *mut lv_obj_t, valid_pos: *const lv_point_t, valid_pos_cnt: u16);
+            }
+        };
+        let cg = CodeGen::load_func_defs(bindgen_code.to_string().as_str()).unwrap();
+        let widget = |name: &str| LvWidget {
+            name: name.to_string(),
+            methods: vec![],
+            enums: vec![],
+        };
+
+        let code = cg[0].code(&widget("line")).unwrap();
+        let expected_code = quote! {
+            pub fn set_points(&mut self, point_a: &'static [crate::Point]) -> crate::LvResult<()> {
+                let point_num = core::convert::TryInto::try_into(point_a.len())
+                    .map_err(|_| crate::LvError::InvalidReference)?;
+                unsafe {
+                    lvgl_sys::lv_line_set_points(self.core.raw()?.as_mut(), point_a.as_ptr() as *const lvgl_sys::lv_point_t, point_num);
+                }
+                Ok(())
+            }
+        };
+        assert_eq!(code.to_string(), expected_code.to_string());
+
+        let code = cg[1].code(&widget("gauge")).unwrap();
+        let expected_code = quote! {
+            pub fn set_needle_count(&mut self, colors: &'static [crate::Color]) -> crate::LvResult<()> {
+                let needle_cnt = core::convert::TryInto::try_into(colors.len())
+                    .map_err(|_| crate::LvError::InvalidReference)?;
+                unsafe {
+                    lvgl_sys::lv_gauge_set_needle_count(self.core.raw()?.as_mut(), needle_cnt, colors.as_ptr() as *const lvgl_sys::lv_color_t);
+                }
+                Ok(())
+            }
+        };
+        assert_eq!(code.to_string(), expected_code.to_string());
+
+        let code = cg[2].code(&widget("label")).unwrap();
+        let expected_code = quote! {
+            pub fn set_text_static(&mut self, text: &'static cstr_core::CStr) -> crate::LvResult<()> {
+                unsafe {
+                    lvgl_sys::lv_label_set_text_static(self.core.raw()?.as_mut(), text.as_ptr());
+                }
+                Ok(())
+            }
+        };
+        assert_eq!(code.to_string(), expected_code.to_string());
+
+        // Arrays of types without a Rust equivalent, or without a length, are left to hand
+        // written wrappers
+        assert_eq!(
+            cg[3].code(&widget("calendar")).err(),
+            Some(WrapperError::UnmappedType(
+                "* mut lv_calendar_date_t".to_string()
+            ))
+        );
+        assert!(cg[4].code(&widget("calendar")).is_err());
+
+        let code = cg[5].code(&widget("tileview")).unwrap();
+        let expected_code = quote! {
+            pub fn set_valid_positions(&mut self, valid_pos: &'static [crate::Point]) -> crate::LvResult<()> {
+                let valid_pos_cnt = core::convert::TryInto::try_into(valid_pos.len())
+                    .map_err(|_| crate::LvError::InvalidReference)?;
+                unsafe {
+                    lvgl_sys::lv_tileview_set_valid_positions(self.core.raw()?.as_mut(), valid_pos.as_ptr() as *const lvgl_sys::lv_point_t, valid_pos_cnt);
+                }
+                Ok(())
+            }
+        };
+        assert_eq!(code.to_string(), expected_code.to_string());
+    }
+
+    #[test]
+    fn config_maps_types_and_skips_functions() {
+        let bindgen_code = quote! {
+            extern "C" {
+                pub fn lv_bar_create(par: *mut lv_obj_t, copy: *const lv_obj_t) -> *mut lv_obj_t;
+                pub fn lv_bar_set_value(bar: *mut lv_obj_t, value: lv_coord_t, anim: lv_anim_enable_t);
+                pub fn lv_bar_set_anim_time(bar: *mut lv_obj_t, anim_time: u16);
+            }
+        };
+        let config = Config {
+            type_mappings: vec![("lv_coord_t".to_string(), "i32".to_string())]
+                .into_iter()
+                .collect(),
+            skip: vec!["lv_bar_set_anim_time".to_string()],
+        };
+        let cg = CodeGen::with_config(bindgen_code.to_string().as_str(), &config).unwrap();
+        let bar = &cg.get_widgets()[0];
+
+        let code = bar.methods[1].code(bar).unwrap();
+        let expected_code = quote! {
+            pub fn set_value(&mut self, value: i32, anim: crate::Animation) -> crate::LvResult<()> {
+                unsafe {
+                    lvgl_sys::lv_bar_set_value(self.core.raw()?.as_mut(), value, anim.into());
+                }
+                Ok(())
+            }
+        };
+        assert_eq!(code.to_string(), expected_code.to_string());
+        assert_eq!(
+            cg.coverage().reason("lv_bar_set_anim_time"),
+            Some(&WrapperError::Skip)
+        );
+    }
+
+    #[test]
+    fn can_parse_doc_comments() {
+        let doc = LvDoc::parse(
+            " Set the start angle of an arc.\n 0 deg: right, 90 bottom, etc.\n @param arc pointer to an arc object\n @param start the start angle\n   in degrees\n @return always `true`",
+        );
+
+        let code = doc.code(&[("start", "angle")]);
+        let expected_code = quote! {
+            #[doc = " Set the start angle of an arc."]
+            #[doc = " 0 deg: right, 90 bottom, etc."]
+            #[doc = ""]
+            #[doc = " * `angle`: the start angle in degrees"]
+            #[doc = ""]
+            #[doc = " Returns always `true`"]
+        };
+
+        assert_eq!(code.to_string(), expected_code.to_string());
+    }
+
+    #[test]
+    fn generate_getter_wrapper() {
+        // pub fn lv_arc_get_angle_end(arc: *const lv_obj_t) -> u16;
+        let arc_get_angle_end = LvFunc::new(
+            "lv_arc_get_angle_end".to_string(),
+            vec![LvArg::new(
+                "arc".to_string(),
+                LvType::new("*const lv_obj_t".to_string()),
+            )],
+            Some(LvType::new("u16".to_string())),
+        );
+        let arc_widget = LvWidget {
+            name: "arc".to_string(),
+            methods: vec![],
+            enums: vec![],
+        };
+
+        let code = arc_get_angle_end.code(&arc_widget).unwrap();
+        let expected_code = quote! {
+            pub fn get_angle_end(&self) -> crate::LvResult<u16> {
+                unsafe {
+                    let value = lvgl_sys::lv_arc_get_angle_end(self.core.raw()?.as_mut());
+                    Ok(value)
+                }
+            }
+        };
+
+        assert_eq!(code.to_string(), expected_code.to_string());
+    }
+
+    #[test]
+    fn generate_getter_wrapper_for_str_return_type() {
+        let bindgen_code = quote! {
+            extern "C" {
+                pub fn lv_label_get_text(label: *const lv_obj_t) -> *mut cty::c_char;
+            }
+        };
+        let cg = CodeGen::load_func_defs(bindgen_code.to_string().as_str()).unwrap();
+
+        let label_get_text = cg.first().unwrap().clone();
+        let parent_widget = LvWidget {
+            name: "label".to_string(),
+            methods: vec![],
+            enums: vec![],
+        };
+
+        let code = label_get_text.code(&parent_widget).unwrap();
+        let expected_code = quote! {
+            pub fn get_text(&self) -> crate::LvResult<&cstr_core::CStr> {
+                unsafe {
+                    let value = lvgl_sys::lv_label_get_text(self.core.raw()?.as_mut());
+                    if value.is_null() {
+                        Err(crate::LvError::InvalidReference)
+                    } else {
+                        Ok(cstr_core::CStr::from_ptr(value))
+                    }
+                }
+            }
+        };
+
+        assert_eq!(code.to_string(), expected_code.to_string());
+    }
+
+    #[test]
+    fn skip_getter_with_unknown_pointer_return_type() {
+        let bindgen_code = quote! {
+            extern "C" {
+                pub fn lv_img_get_src(img: *mut lv_obj_t) -> *const cty::c_void;
+            }
+        };
+        let cg = CodeGen::load_func_defs(bindgen_code.to_string().as_str()).unwrap();
+        let parent_widget = LvWidget {
+            name: "img".to_string(),
+            methods: vec![],
+            enums: vec![],
+        };
+
+        assert!(cg[0].code(&parent_widget).is_err());
+    }
+
     #[test]
     fn generate_basic_widget_code() {
         let arc_widget = LvWidget {
             name: "arc".to_string(),
             methods: vec![],
+            enums: vec![],
         };
 
         let code = arc_widget.code(&()).unwrap();
@@ -606,6 +2176,26 @@ mod test {
         assert_eq!(code.to_string(), expected_code.to_string());
     }
 
+    #[test]
+    fn generate_widget_with_special_events() {
+        let textarea_widget = LvWidget {
+            name: "textarea".to_string(),
+            methods: vec![],
+            enums: vec![],
+        };
+
+        let code = textarea_widget.code(&()).unwrap();
+        let expected_code = quote! {
+            define_object!(Textarea, event = TextareaEvent);
+
+            impl Textarea {
+
+            }
+        };
+
+        assert_eq!(code.to_string(), expected_code.to_string());
+    }
+
     #[test]
     fn generate_widget_with_constructor_code() {
         // pub fn lv_arc_create(par: *mut lv_obj_t, copy: *const lv_obj_t) -> *mut lv_obj_t;
@@ -624,6 +2214,7 @@ mod test {
         let arc_widget = LvWidget {
             name: "arc".to_string(),
             methods: vec![arc_create],
+            enums: vec![],
         };
 
         let code = arc_widget.code(&()).unwrap();
@@ -646,6 +2237,436 @@ mod test {
                         }
                     }
                 }
+
+                pub fn new_copy<C>(parent: &mut C, copy: &Self) -> crate::LvResult<Self>
+                where
+                    C: crate::NativeObject,
+                {
+                    unsafe {
+                        let ptr = lvgl_sys::lv_arc_create(parent.raw()?.as_mut(), copy.raw()?.as_ptr());
+                        if let Some(raw) = core::ptr::NonNull::new(ptr) {
+                            let core = <crate::Obj as crate::Widget>::from_raw(raw);
+                            Ok(Self { core })
+                        } else {
+                            Err(crate::LvError::InvalidReference)
+                        }
+                    }
+                }
+            }
+        };
+
+        assert_eq!(code.to_string(), expected_code.to_string());
+    }
+
+    #[test]
+    fn skip_hand_written_copy_constructor() {
+        let btnmatrix_create = LvFunc::new(
+            "lv_btnmatrix_create".to_string(),
+            vec![
+                LvArg::new("par".to_string(), LvType::new("*mut lv_obj_t".to_string())),
+                LvArg::new(
+                    "copy".to_string(),
+                    LvType::new("*const lv_obj_t".to_string()),
+                ),
+            ],
+            Some(LvType::new("*mut lv_obj_t".to_string())),
+        );
+        let btnmatrix_widget = LvWidget {
+            name: "btnmatrix".to_string(),
+            methods: vec![],
+            enums: vec![],
+        };
+
+        let code = btnmatrix_create
+            .code(&btnmatrix_widget)
+            .unwrap()
+            .to_string();
+        assert!(code.contains("pub fn new <"));
+        assert!(!code.contains("new_copy"));
+    }
+
+    #[test]
+    fn can_load_widget_enums() {
+        let bindgen_code = quote! {
+            pub const LV_BAR_PART_BG: _bindgen_ty_41 = 0;
+            pub const LV_BAR_PART_INDIC: _bindgen_ty_41 = 1;
+            pub const _LV_BAR_PART_VIRTUAL_LAST: _bindgen_ty_41 = 2;
+            pub const LV_BAR_TYPE_NORMAL: _bindgen_ty_40 = 0;
+            pub const LV_BAR_TYPE_SYMMETRICAL: _bindgen_ty_40 = 1;
+            pub const LV_BAR_ANIM_STATE_INV: u32 = 4294967295;
+            pub const LV_LABEL_LONG_EXPAND: _bindgen_ty_42 = 0;
+            pub const LV_LABEL_LONG_DOT: _bindgen_ty_42 = 2;
+            pub const LV_LIST_PART_BG: _bindgen_ty_43 = 0;
+            pub const LV_LIST_PART_SCROLLBAR: _bindgen_ty_43 = 1;
+            pub const LV_LIST_PART_BACKGROUND: _bindgen_ty_43 = 0;
+        };
+        let widget_names = vec!["bar".to_string(), "label".to_string(), "list".to_string()];
+
+        let enums = CodeGen::load_enums(bindgen_code.to_string().as_str(), &widget_names).unwrap();
+
+        assert_eq!(enums.len(), 4);
+        let (widget, bar_part) = &enums[0];
+        assert_eq!(widget, "bar");
+        assert_eq!(bar_part.name, "BarPart");
+        assert_eq!(bar_part.native_type, "lv_bar_part_t");
+        assert_eq!(bar_part.variants.len(), 2);
+        assert_eq!(bar_part.variants[1].name, "Indic");
+        assert_eq!(enums[1].1.name, "BarType");
+        assert_eq!(enums[2].1.name, "LabelLongMode");
+        assert_eq!(enums[2].1.native_type, "lv_label_long_mode_t");
+        // Aliases are skipped
+        assert_eq!(enums[3].1.variants.len(), 2);
+    }
+
+    #[test]
+    fn generate_widget_with_part_enum() {
+        let bar_set_type = LvFunc::new(
+            "lv_bar_set_type".to_string(),
+            vec![
+                LvArg::new("bar".to_string(), LvType::new("*mut lv_obj_t".to_string())),
+                LvArg::new("type".to_string(), LvType::new("lv_bar_type_t".to_string())),
+            ],
+            None,
+        );
+        let bar_widget = LvWidget {
+            name: "bar".to_string(),
+            methods: vec![bar_set_type],
+            enums: vec![
+                LvEnum {
+                    name: "BarPart".to_string(),
+                    native_type: "lv_bar_part_t".to_string(),
+                    variants: vec![LvEnumVariant {
+                        name: "Bg".to_string(),
+                        const_name: "LV_BAR_PART_BG".to_string(),
+                        value: 0,
+                    }],
+                },
+                LvEnum {
+                    name: "BarType".to_string(),
+                    native_type: "lv_bar_type_t".to_string(),
+                    variants: vec![LvEnumVariant {
+                        name: "Normal".to_string(),
+                        const_name: "LV_BAR_TYPE_NORMAL".to_string(),
+                        value: 0,
+                    }],
+                },
+            ],
+        };
+
+        let code = bar_widget.code(&()).unwrap();
+        let expected_code = quote! {
+            #[derive(Debug, Copy, Clone, PartialEq)]
+            #[repr(u8)]
+            pub enum BarPart {
+                Bg = lvgl_sys::LV_BAR_PART_BG as u8,
+                All = lvgl_sys::LV_OBJ_PART_ALL as u8
+            }
+
+            impl From<BarPart> for u8 {
+                fn from(value: BarPart) -> Self {
+                    value as u8
+                }
+            }
+
+            #[derive(Debug, Copy, Clone, PartialEq)]
+            #[repr(u8)]
+            pub enum BarType {
+                Normal = lvgl_sys::LV_BAR_TYPE_NORMAL as u8
+            }
+
+            impl From<BarType> for u8 {
+                fn from(value: BarType) -> Self {
+                    value as u8
+                }
+            }
+
+            define_object!(Bar, part = BarPart);
+
+            impl Bar {
+                pub fn set_type(&mut self, r#type: BarType) -> crate::LvResult<()> {
+                    unsafe {
+                        lvgl_sys::lv_bar_set_type(self.core.raw()?.as_mut(), r#type.into());
+                    }
+                    Ok(())
+                }
+            }
+        };
+
+        assert_eq!(code.to_string(), expected_code.to_string());
+    }
+
+    #[test]
+    fn coverage_report_lists_skipped_functions() {
+        let bindgen_code = quote! {
+            extern "C" {
+                pub fn lv_bar_create(par: *mut lv_obj_t, copy: *const lv_obj_t) -> *mut lv_obj_t;
+                pub fn lv_bar_set_range(bar: *mut lv_obj_t, min: i16, max: i16);
+                pub fn lv_bar_set_anim_time(bar: *mut lv_obj_t, anim_time: u16);
+                pub fn lv_bar_get_style(bar: *mut lv_obj_t) -> *const lv_style_t;
+                pub fn lv_bar_set_start_value(bar: *mut lv_obj_t, value: i16, anim: lv_anim_enable_t, style: *const lv_style_t);
+                pub fn lv_btnmatrix_create(par: *mut lv_obj_t, copy: *const lv_obj_t) -> *mut lv_obj_t;
+                pub fn lv_btnmatrix_get_map_array(btnm: *const lv_obj_t) -> *mut *const cty::c_char;
+                pub fn lv_init();
+            }
+        };
+
+        let cg = CodeGen::from(bindgen_code.to_string().as_str()).unwrap();
+        let report = cg.coverage();
+
+        assert_eq!(
+            report.wrapped,
+            vec![
+                "lv_bar_create",
+                "lv_bar_set_range",
+                "lv_bar_set_anim_time",
+                "lv_btnmatrix_create"
+            ]
+        );
+        assert_eq!(report.skipped.len(), 4);
+        assert_eq!(
+            report.reason("lv_bar_get_style"),
+            Some(&WrapperError::UnmappedType(
+                "* const lv_style_t".to_string()
+            ))
+        );
+        assert_eq!(
+            report.reason("lv_init"),
+            Some(&WrapperError::NotAWidgetMethod)
+        );
+        assert_eq!(report.unmapped_types().get("* const lv_style_t"), Some(&2));
+        assert_eq!(
+            report.unmapped_types().get("* mut * const cty :: c_char"),
+            Some(&1)
+        );
+        assert!(report
+            .to_string()
+            .starts_with("4 of 8 LVGL functions wrapped (50%)"));
+    }
+
+    #[test]
+    fn generate_module_code() {
+        let bindgen_code = quote! {
+            extern "C" {
+                pub fn lv_group_create() -> *mut lv_group_t;
+                pub fn lv_group_del(group: *mut lv_group_t);
+                pub fn lv_group_add_obj(group: *mut lv_group_t, obj: *mut lv_obj_t);
+                pub fn lv_group_get_editing(group: *const lv_group_t) -> bool;
+                pub fn lv_indev_set_group(indev: *mut lv_indev_t, group: *mut lv_group_t);
+                pub fn lv_img_decoder_create() -> *mut lv_img_decoder_t;
+                pub fn lv_img_buf_free(dsc: *mut lv_img_dsc_t);
+            }
+        };
+        let cg = CodeGen::from(bindgen_code.to_string().as_str()).unwrap();
+        let group = cg
+            .get_modules()
+            .iter()
+            .find(|m| m.rust_type == "Group")
+            .unwrap();
+
+        let code = group.code(&()).unwrap();
+        let expected_code = quote! {
+            define_module!(Group, lvgl_sys::lv_group_t);
+
+            impl Group {
+                pub fn create() -> crate::LvResult<Option<crate::Group> > {
+                    unsafe {
+                        let value = lvgl_sys::lv_group_create();
+                        Ok(core::ptr::NonNull::new(value).map(|raw| crate::Group::from_raw(raw)))
+                    }
+                }
+
+                /// Release the object. Other handles to the same object, e.g. returned by a
+                /// getter, must not be used afterwards.
+                pub fn delete(self) {
+                    unsafe {
+                        lvgl_sys::lv_group_del(self.raw());
+                    }
+                }
+
+                pub fn add_obj(&mut self, obj: &impl crate::NativeObject) -> crate::LvResult<()> {
+                    unsafe {
+                        lvgl_sys::lv_group_add_obj(self.raw(), obj.raw()?.as_ptr());
+                    }
+                    Ok(())
+                }
+
+                pub fn get_editing(&self) -> crate::LvResult<bool> {
+                    unsafe {
+                        let value = lvgl_sys::lv_group_get_editing(self.raw());
+                        Ok(value)
+                    }
+                }
+            }
+        };
+        assert_eq!(code.to_string(), expected_code.to_string());
+
+        let indev = cg
+            .get_modules()
+            .iter()
+            .find(|m| m.rust_type == "InputDevice")
+            .unwrap();
+        let code = indev.functions[0].module_code(indev).unwrap();
+        let expected_code = quote! {
+            pub fn set_group(&mut self, group: &crate::Group) -> crate::LvResult<()> {
+                unsafe {
+                    lvgl_sys::lv_indev_set_group(self.raw(), group.raw());
+                }
+                Ok(())
+            }
+        };
+        assert_eq!(code.to_string(), expected_code.to_string());
+
+        // The image buffer functions are not part of any module
+        let modules: Vec<(&str, &str)> = cg
+            .get_modules()
+            .iter()
+            .flat_map(|m| {
+                m.functions
+                    .iter()
+                    .map(move |f| (m.rust_type.as_str(), f.name.as_str()))
+            })
+            .filter(|(_, f)| f.starts_with("lv_img_"))
+            .collect();
+        assert_eq!(modules, vec![("ImageDecoder", "lv_img_decoder_create")]);
+    }
+
+    #[test]
+    fn generate_screen_constructor_for_containers() {
+        let bindgen_code = quote! {
+            extern "C" {
+                pub fn lv_cont_create(par: *mut lv_obj_t, copy: *const lv_obj_t) -> *mut lv_obj_t;
+            }
+        };
+        let cg = CodeGen::from(bindgen_code.to_string().as_str()).unwrap();
+
+        let code = cg.get_widgets()[0].code(&()).unwrap().to_string();
+        let expected_code = quote! {
+            pub fn new_screen() -> crate::LvResult<Self> {
+                unsafe {
+                    let ptr = lvgl_sys::lv_cont_create(core::ptr::null_mut(), core::ptr::null_mut());
+                    if let Some(raw) = core::ptr::NonNull::new(ptr) {
+                        let core = <crate::Obj as crate::Widget>::from_raw(raw);
+                        Ok(Self { core })
+                    } else {
+                        Err(crate::LvError::InvalidReference)
+                    }
+                }
+            }
+        };
+
+        assert!(code.contains(expected_code.to_string().as_str()));
+    }
+
+    #[test]
+    fn generate_widget_trait_methods() {
+        let bindgen_code = quote! {
+            extern "C" {
+                pub fn lv_obj_create(par: *mut lv_obj_t, copy: *const lv_obj_t) -> *mut lv_obj_t;
+                pub fn lv_obj_set_click(obj: *mut lv_obj_t, en: bool);
+                pub fn lv_obj_set_width(obj: *mut lv_obj_t, w: lv_coord_t);
+                pub fn lv_obj_get_parent(obj: *const lv_obj_t) -> *mut lv_obj_t;
+                pub fn lv_obj_clean(obj: *mut lv_obj_t);
+            }
+        };
+        let cg = CodeGen::from(bindgen_code.to_string().as_str()).unwrap();
+
+        let code = cg.get_base_widget().unwrap().base_methods_code();
+        let expected_code = quote! {
+            macro_rules! widget_methods {
+                () => {
+                    fn set_click(&mut self, en: bool) -> crate::LvResult<()> {
+                        unsafe {
+                            lvgl_sys::lv_obj_set_click(self.raw()?.as_mut(), en);
+                        }
+                        Ok(())
+                    }
+
+                    fn get_parent(&self) -> crate::LvResult<Option<crate::Obj> > {
+                        unsafe {
+                            let value = lvgl_sys::lv_obj_get_parent(self.raw()?.as_mut());
+                            Ok(core::ptr::NonNull::new(value)
+                                .map(|raw| <crate::Obj as crate::Widget>::from_raw(raw)))
+                        }
+                    }
+                };
+            }
+        };
+
+        assert_eq!(code.to_string(), expected_code.to_string());
+    }
+
+    const LVGL_STYLE_IDS: StyleIds = StyleIds {
+        value: 0x0,
+        color: 0x9,
+        opa: 0xC,
+        ptr: 0xE,
+    };
+
+    #[test]
+    fn can_load_style_props() {
+        let bindgen_code = quote! {
+            pub const LV_STYLE_ID_VALUE: u32 = 0;
+            pub const LV_STYLE_ID_COLOR: u32 = 9;
+            pub const LV_STYLE_ID_OPA: u32 = 12;
+            pub const LV_STYLE_ID_PTR: u32 = 14;
+            pub const LV_STYLE_STATE_POS: u32 = 8;
+            pub const LV_STYLE_RADIUS: _bindgen_ty_19 = 1;
+            pub const LV_STYLE_BG_COLOR: _bindgen_ty_19 = 41;
+            pub const LV_STYLE_BG_OPA: _bindgen_ty_19 = 44;
+            pub const LV_STYLE_TEXT_FONT: _bindgen_ty_19 = 32910;
+            pub type _bindgen_ty_19 = u32;
+        };
+
+        let props = CodeGen::load_style_props(bindgen_code.to_string().as_str()).unwrap();
+        let ids = CodeGen::load_style_ids(bindgen_code.to_string().as_str()).unwrap();
+
+        assert_eq!(ids, LVGL_STYLE_IDS);
+        assert_eq!(props.len(), 4);
+        assert_eq!(props[0].name, "radius");
+        assert_eq!(props[0].value_type(&ids), StyleValueType::Int);
+        assert_eq!(props[1].value_type(&ids), StyleValueType::Color);
+        assert_eq!(props[2].value_type(&ids), StyleValueType::Opa);
+        assert_eq!(props[3].name, "text_font");
+        assert_eq!(props[3].value_type(&ids), StyleValueType::Ptr);
+        assert!(CodeGen::load_style_ids("pub const LV_STYLE_ID_VALUE: u32 = 0;").is_err());
+    }
+
+    #[test]
+    fn generate_style_code() {
+        let style = LvStyle {
+            props: vec![
+                LvStyleProp::new("radius".to_string(), 1),
+                LvStyleProp::new("value_str".to_string(), 127),
+                LvStyleProp::new("unknown_ptr".to_string(), 255),
+            ],
+            ids: LVGL_STYLE_IDS,
+        };
+
+        let code = style.code(&()).unwrap();
+        let expected_code = quote! {
+            bitflags! {
+                pub struct StyleProp: u32 {
+                    const RADIUS = lvgl_sys::LV_STYLE_RADIUS;
+                    const VALUE_STR = lvgl_sys::LV_STYLE_VALUE_STR;
+                }
+            }
+
+            impl Style {
+                pub fn set_radius(&mut self, state: crate::State, value: i16) {
+                    self.set_int(lvgl_sys::LV_STYLE_RADIUS, state, value);
+                }
+
+                pub fn get_radius(&self, state: crate::State) -> Option<i16> {
+                    self.get_int(lvgl_sys::LV_STYLE_RADIUS, state)
+                }
+
+                pub fn set_value_str(&mut self, state: crate::State, value: &cstr_core::CStr) -> crate::LvResult<()> {
+                    self.set_str(lvgl_sys::LV_STYLE_VALUE_STR, state, value)
+                }
+
+                pub fn get_value_str(&self, state: crate::State) -> Option<&cstr_core::CStr> {
+                    self.get_str(lvgl_sys::LV_STYLE_VALUE_STR, state)
+                }
             }
         };
 
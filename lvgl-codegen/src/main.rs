@@ -0,0 +1,79 @@
+use lvgl_codegen::{CodeGen, Config};
+use std::error::Error;
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::process::Command;
+use structopt::StructOpt;
+
+/// Generate the safe Rust wrappers of LVGL, to inspect and diff them between LVGL versions.
+#[derive(StructOpt)]
+#[structopt(name = "lvgl-codegen")]
+struct Opt {
+    /// The bindings generated by bindgen, or an LVGL header with `--header`
+    #[structopt(parse(from_os_str))]
+    input: PathBuf,
+
+    /// The input is a C header, e.g. `lvgl.h`, to generate the bindings of
+    #[structopt(long)]
+    header: bool,
+
+    /// Argument passed to clang when generating the bindings of a header, e.g. `-I<dir>`
+    #[structopt(long = "clang-arg", number_of_values = 1)]
+    clang_args: Vec<String>,
+
+    /// TOML file with the `type_mappings` and `skip` settings
+    #[structopt(short, long, parse(from_os_str))]
+    config: Option<PathBuf>,
+
+    /// Directory where the generated sources are written
+    #[structopt(short, long, parse(from_os_str), default_value = ".")]
+    out_dir: PathBuf,
+}
+
+fn main() -> Result<(), Box<dyn Error>> {
+    let opt = Opt::from_args();
+
+    let config = match &opt.config {
+        Some(path) => toml::from_str(&fs::read_to_string(path)?)?,
+        None => Config::default(),
+    };
+    let bindings = if opt.header {
+        header_bindings(&opt.input, &opt.clang_args)?
+    } else {
+        fs::read_to_string(&opt.input)?
+    };
+
+    let codegen = CodeGen::with_config(&bindings, &config)?;
+    fs::create_dir_all(&opt.out_dir)?;
+    let paths = codegen.write_files(&opt.out_dir)?;
+
+    // The generated code is a single line, format it so it can be read and diffed
+    let status = Command::new("rustfmt")
+        .args(["--edition", "2018"])
+        .args(&paths)
+        .status();
+    if !matches!(status, Ok(status) if status.success()) {
+        eprintln!("warning: could not format the generated sources with rustfmt");
+    }
+    Ok(())
+}
+
+#[cfg(feature = "header")]
+fn header_bindings(header: &Path, clang_args: &[String]) -> Result<String, Box<dyn Error>> {
+    // Same settings as `lvgl-sys`
+    let bindings = bindgen::Builder::default()
+        .header(header.to_string_lossy())
+        .generate_comments(true)
+        .layout_tests(false)
+        .use_core()
+        .ctypes_prefix("cty")
+        .clang_args(clang_args)
+        .generate()
+        .map_err(|_| "unable to generate the bindings of the header")?;
+    Ok(bindings.to_string())
+}
+
+#[cfg(not(feature = "header"))]
+fn header_bindings(_header: &Path, _clang_args: &[String]) -> Result<String, Box<dyn Error>> {
+    Err("lvgl-codegen was built without the `header` feature".into())
+}
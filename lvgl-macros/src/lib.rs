@@ -0,0 +1,252 @@
+//! Procedural macros of [`lvgl`](https://github.com/rafaelcaricio/lvgl-rs), re-exported by it
+//! with the `macros` feature.
+
+use image::RgbaImage;
+use proc_macro::TokenStream;
+use proc_macro2::Span;
+use quote::quote;
+use std::collections::HashMap;
+use std::path::PathBuf;
+use syn::parse::{Parse, ParseStream};
+use syn::{parse_macro_input, Ident, LitByteStr, LitStr, Token};
+
+/// Include an image file as a `&'static lvgl::ImageDescriptor`, converted when building.
+///
+/// The path is relative to the directory of the `Cargo.toml` of the crate using the macro.
+/// The format is one of the variants of `lvgl::ColorFormat`, `TrueColorAlpha` by default:
+///
+/// ```ignore
+/// static LOGO: &ImageDescriptor = include_lv_img!("assets/logo.png");
+/// static ICON: &ImageDescriptor = include_lv_img!("assets/icon.png", format = Indexed4Bit);
+/// ```
+///
+/// True color images are converted to `LV_COLOR_DEPTH` by the `lvgl` crate, the transparent
+/// pixels of `TrueColorChromaKeyed` images become `LV_COLOR_TRANSP`. Indexed images fail to
+/// build if they have more colors than their palette holds, they are not quantized. Alpha only
+/// images keep the most significant bits of the alpha channel.
+#[proc_macro]
+pub fn include_lv_img(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as ImageInput);
+    match input.expand() {
+        Ok(tokens) => tokens.into(),
+        Err(err) => err.to_compile_error().into(),
+    }
+}
+
+struct ImageInput {
+    path: LitStr,
+    format: Ident,
+}
+
+impl Parse for ImageInput {
+    fn parse(input: ParseStream) -> syn::Result<Self> {
+        let path = input.parse()?;
+        let mut format = Ident::new("TrueColorAlpha", Span::call_site());
+        if input.parse::<Option<Token![,]>>()?.is_some() && !input.is_empty() {
+            let key: Ident = input.parse()?;
+            if key != "format" {
+                return Err(syn::Error::new(key.span(), "expected `format = ...`"));
+            }
+            input.parse::<Token![=]>()?;
+            format = input.parse()?;
+            input.parse::<Option<Token![,]>>()?;
+        }
+        Ok(Self { path, format })
+    }
+}
+
+impl ImageInput {
+    fn expand(&self) -> syn::Result<proc_macro2::TokenStream> {
+        let format = Format::parse(&self.format)?;
+        let error = |msg: String| syn::Error::new(self.path.span(), msg);
+
+        let dir = std::env::var("CARGO_MANIFEST_DIR").map_err(|err| error(err.to_string()))?;
+        let path = PathBuf::from(dir).join(self.path.value());
+        let image = image::open(&path)
+            .map_err(|err| error(format!("cannot read {}: {}", path.display(), err)))?
+            .to_rgba8();
+        let (width, height) = image.dimensions();
+        if width >= 1 << 11 || height >= 1 << 11 {
+            return Err(error(format!(
+                "{}x{} is too large, LVGL images are at most 2047x2047",
+                width, height
+            )));
+        }
+        let (width, height) = (width as u16, height as u16);
+
+        let path = path.to_string_lossy();
+        let variant = &self.format;
+        let data = match format {
+            // The size of the colors depends on `LV_COLOR_DEPTH`, only known by `lvgl`
+            Format::TrueColor => {
+                let rgba = LitByteStr::new(image.as_raw(), Span::call_site());
+                quote! {
+                    const SIZE: usize = FORMAT.buffer_size(#width, #height);
+                    static DATA: [u8; SIZE] = FORMAT.from_rgba::<SIZE>(#rgba);
+                }
+            }
+            Format::Indexed(bits) => {
+                let data = indexed(&image, bits).map_err(error)?;
+                let data = LitByteStr::new(&data, Span::call_site());
+                quote!(static DATA: &[u8] = #data;)
+            }
+            Format::Alpha(bits) => {
+                let data = LitByteStr::new(&alpha(&image, bits), Span::call_site());
+                quote!(static DATA: &[u8] = #data;)
+            }
+        };
+        let data_ref = match format {
+            Format::TrueColor => quote!(&DATA),
+            _ => quote!(DATA),
+        };
+
+        Ok(quote! {{
+            // Rebuild when the image changes
+            const _: &[u8] = include_bytes!(#path);
+            const FORMAT: ::lvgl::ColorFormat = ::lvgl::ColorFormat::#variant;
+            #data
+            static IMAGE: ::lvgl::ImageDescriptor =
+                ::lvgl::ImageDescriptor::new(#data_ref, #width, #height, FORMAT);
+            &IMAGE
+        }})
+    }
+}
+
+#[derive(Debug, Copy, Clone, PartialEq)]
+enum Format {
+    TrueColor,
+    Indexed(u8),
+    Alpha(u8),
+}
+
+impl Format {
+    fn parse(ident: &Ident) -> syn::Result<Self> {
+        let format = match ident.to_string().as_str() {
+            "TrueColor" | "TrueColorAlpha" | "TrueColorChromaKeyed" => Format::TrueColor,
+            "Indexed1Bit" => Format::Indexed(1),
+            "Indexed2Bit" => Format::Indexed(2),
+            "Indexed4Bit" => Format::Indexed(4),
+            "Indexed8Bit" => Format::Indexed(8),
+            "Alpha1Bit" => Format::Alpha(1),
+            "Alpha2Bit" => Format::Alpha(2),
+            "Alpha4Bit" => Format::Alpha(4),
+            "Alpha8Bit" => Format::Alpha(8),
+            _ => {
+                return Err(syn::Error::new(
+                    ident.span(),
+                    "unknown format, expected a variant of `lvgl::ColorFormat`",
+                ))
+            }
+        };
+        Ok(format)
+    }
+}
+
+/// The palette of `lv_color32_t` colors, followed by the indices of the pixels.
+fn indexed(image: &RgbaImage, bits: u8) -> Result<Vec<u8>, String> {
+    let max_colors = 1 << bits;
+    let mut palette = Vec::new();
+    let mut indices = HashMap::new();
+    let mut pixels = Vec::with_capacity(image.len() / 4);
+    for pixel in image.pixels() {
+        let next = palette.len();
+        let index = *indices.entry(pixel.0).or_insert(next);
+        if index == next {
+            palette.push(pixel.0);
+        }
+        pixels.push(index as u8);
+    }
+    if palette.len() > max_colors {
+        return Err(format!(
+            "the image has {} colors, a {} bits palette holds {}",
+            palette.len(),
+            bits,
+            max_colors
+        ));
+    }
+    palette.resize(max_colors, [0; 4]);
+
+    let mut data: Vec<u8> = palette.iter().flat_map(|&[r, g, b, a]| [b, g, r, a]).collect();
+    data.extend(pack(&pixels, image.width() as usize, bits));
+    Ok(data)
+}
+
+/// The opacity of the pixels, with the given number of bits each.
+fn alpha(image: &RgbaImage, bits: u8) -> Vec<u8> {
+    let opacity: Vec<u8> = image.pixels().map(|pixel| pixel.0[3] >> (8 - bits)).collect();
+    pack(&opacity, image.width() as usize, bits)
+}
+
+/// Pack `values` of `bits` bits, the first pixel in the most significant bits. Each row starts on
+/// a new byte.
+fn pack(values: &[u8], width: usize, bits: u8) -> Vec<u8> {
+    let per_byte = 8 / bits as usize;
+    values
+        .chunks(width)
+        .flat_map(|row| {
+            row.chunks(per_byte).map(|pixels| {
+                pixels.iter().enumerate().fold(0, |byte, (i, value)| {
+                    byte | value << (8 - bits as usize * (i + 1))
+                })
+            })
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use image::Rgba;
+
+    #[test]
+    fn pack_rows_from_the_most_significant_bit() {
+        assert_eq!(pack(&[1, 0, 1], 3, 1), vec![0b1010_0000]);
+        assert_eq!(pack(&[1, 1, 1, 1], 2, 1), vec![0b1100_0000, 0b1100_0000]);
+        assert_eq!(pack(&[3, 2, 1, 0, 3], 5, 2), vec![0b1110_0100, 0b1100_0000]);
+        assert_eq!(pack(&[0xa, 0x5], 2, 4), vec![0xa5]);
+        assert_eq!(pack(&[0x12, 0x34], 1, 8), vec![0x12, 0x34]);
+    }
+
+    #[test]
+    fn alpha_keeps_the_most_significant_bits() {
+        let mut image = RgbaImage::new(3, 1);
+        image.put_pixel(0, 0, Rgba([0, 0, 0, 0xff]));
+        image.put_pixel(1, 0, Rgba([0, 0, 0, 0x7f]));
+        image.put_pixel(2, 0, Rgba([0, 0, 0, 0x80]));
+        assert_eq!(alpha(&image, 1), vec![0b1010_0000]);
+        assert_eq!(alpha(&image, 4), vec![0xf7, 0x80]);
+        assert_eq!(alpha(&image, 8), vec![0xff, 0x7f, 0x80]);
+    }
+
+    #[test]
+    fn indexed_starts_with_the_palette() {
+        let red = Rgba([0xff, 0, 0, 0xff]);
+        let blue = Rgba([0, 0, 0xff, 0x80]);
+        let image = RgbaImage::from_fn(3, 1, |x, _| if x == 1 { blue } else { red });
+
+        let data = indexed(&image, 1).unwrap();
+        assert_eq!(
+            data,
+            vec![0, 0, 0xff, 0xff, 0xff, 0, 0, 0x80, 0b0100_0000],
+            "BGRA palette then indices"
+        );
+        assert_eq!(indexed(&image, 2).unwrap().len(), 4 * 4 + 1);
+    }
+
+    #[test]
+    fn indexed_fails_with_too_many_colors() {
+        let image = RgbaImage::from_fn(3, 1, |x, _| Rgba([x as u8, 0, 0, 0xff]));
+        assert!(indexed(&image, 1).is_err());
+        assert!(indexed(&image, 2).is_ok());
+    }
+
+    #[test]
+    fn parse_format_argument() {
+        let input: ImageInput = syn::parse_str(r#""logo.png""#).unwrap();
+        assert_eq!(input.format, "TrueColorAlpha");
+        let input: ImageInput = syn::parse_str(r#""logo.png", format = Alpha4Bit"#).unwrap();
+        assert_eq!(Format::parse(&input.format).unwrap(), Format::Alpha(4));
+        assert!(syn::parse_str::<ImageInput>(r#""logo.png", colors = Alpha4Bit"#).is_err());
+        assert!(Format::parse(&Ident::new("Rgb565", Span::call_site())).is_err());
+    }
+}
@@ -0,0 +1,95 @@
+use crate::{AnimPath, LvResult, NativeObject, Widget};
+use core::cell::UnsafeCell;
+use core::mem::MaybeUninit;
+use core::time::Duration;
+
+/// A property of an object changed by an `Anim`.
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub enum AnimProp {
+    X,
+    Y,
+    Width,
+    Height,
+}
+
+impl AnimProp {
+    fn setter(self) -> unsafe extern "C" fn(*mut lvgl_sys::lv_obj_t, lvgl_sys::lv_coord_t) {
+        match self {
+            AnimProp::X => lvgl_sys::lv_obj_set_x,
+            AnimProp::Y => lvgl_sys::lv_obj_set_y,
+            AnimProp::Width => lvgl_sys::lv_obj_set_width,
+            AnimProp::Height => lvgl_sys::lv_obj_set_height,
+        }
+    }
+}
+
+/// An animation of a property of an object, e.g. to slide it in.
+///
+/// `start` runs a copy of the animation, so it can be started again or dropped. LVGL stops the
+/// animations of an object when it is deleted.
+///
+/// ```ignore
+/// let mut anim = Anim::new(&button, AnimProp::X)?;
+/// anim.set_values(-100, 30);
+/// anim.set_time(Duration::from_millis(500));
+/// anim.set_path(&AnimPath::EASE_OUT);
+/// anim.start()?;
+/// ```
+pub struct Anim {
+    raw: UnsafeCell<lvgl_sys::lv_anim_t>,
+}
+
+impl Anim {
+    /// Animate `prop` of `obj`, from its current value to itself until the values are set.
+    pub fn new<W: Widget>(obj: &W, prop: AnimProp) -> LvResult<Self> {
+        crate::lvgl_init();
+        let obj = obj.raw()?;
+        let mut raw = MaybeUninit::<lvgl_sys::lv_anim_t>::uninit();
+        unsafe {
+            lvgl_sys::lv_anim_init(raw.as_mut_ptr());
+            let mut raw = raw.assume_init();
+            raw.var = obj.as_ptr() as *mut cty::c_void;
+            // LVGL calls the setter with the object as its `void *` variable
+            raw.exec_cb = Some(core::mem::transmute::<
+                unsafe extern "C" fn(*mut lvgl_sys::lv_obj_t, lvgl_sys::lv_coord_t),
+                unsafe extern "C" fn(*mut cty::c_void, lvgl_sys::lv_anim_value_t),
+            >(prop.setter()));
+            Ok(Self {
+                raw: UnsafeCell::new(raw),
+            })
+        }
+    }
+
+    /// The values of the property at the start and at the end of the animation.
+    pub fn set_values(&mut self, start: i16, end: i16) {
+        let raw = self.raw.get_mut();
+        raw.start = start.into();
+        raw.current = start.into();
+        raw.end = end.into();
+    }
+
+    /// How long the animation runs.
+    pub fn set_time(&mut self, time: Duration) {
+        self.raw.get_mut().time = millis(time);
+    }
+
+    /// How long to wait before the animation starts.
+    pub fn set_delay(&mut self, delay: Duration) {
+        self.raw.get_mut().act_time = -millis(delay);
+    }
+
+    /// How the value changes over time, `AnimPath::LINEAR` by default. The path is copied.
+    pub fn set_path(&mut self, path: &AnimPath) {
+        unsafe {
+            self.raw.get_mut().path = *path.raw();
+        }
+    }
+
+    pub(crate) fn raw(&self) -> *mut lvgl_sys::lv_anim_t {
+        self.raw.get()
+    }
+}
+
+fn millis(duration: Duration) -> i32 {
+    core::cmp::min(duration.as_millis(), i32::MAX as u128) as i32
+}
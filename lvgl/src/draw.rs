@@ -0,0 +1,306 @@
+//! Descriptors of how to draw shapes, text and images, e.g. on a `Canvas`.
+//!
+//! ```ignore
+//! let rect = DrawRect::new()
+//!     .radius(4)
+//!     .bg_color(Color::from_rgb((255, 0, 0)))
+//!     .border_width(2);
+//! canvas.draw_rect(10, 10, 50, 30, &rect)?;
+//! ```
+
+use crate::style::Opacity;
+use crate::{Color, Font, Point};
+use core::mem::MaybeUninit;
+
+/// How to draw a rectangle or a polygon.
+#[derive(Clone)]
+pub struct DrawRect {
+    pub(crate) raw: lvgl_sys::lv_draw_rect_dsc_t,
+}
+
+impl DrawRect {
+    pub fn new() -> Self {
+        let raw = unsafe {
+            let mut raw = MaybeUninit::<lvgl_sys::lv_draw_rect_dsc_t>::uninit();
+            lvgl_sys::lv_draw_rect_dsc_init(raw.as_mut_ptr());
+            raw.assume_init()
+        };
+        Self { raw }
+    }
+
+    pub fn radius(mut self, radius: i16) -> Self {
+        self.raw.radius = radius;
+        self
+    }
+
+    pub fn bg_color(mut self, color: Color) -> Self {
+        self.raw.bg_color = color.raw;
+        self
+    }
+
+    pub fn bg_opa(mut self, opa: Opacity) -> Self {
+        self.raw.bg_opa = opa.into();
+        self
+    }
+
+    pub fn border_color(mut self, color: Color) -> Self {
+        self.raw.border_color = color.raw;
+        self
+    }
+
+    pub fn border_width(mut self, width: i16) -> Self {
+        self.raw.border_width = width;
+        self
+    }
+
+    pub fn border_opa(mut self, opa: Opacity) -> Self {
+        self.raw.border_opa = opa.into();
+        self
+    }
+
+    pub fn outline_color(mut self, color: Color) -> Self {
+        self.raw.outline_color = color.raw;
+        self
+    }
+
+    pub fn outline_width(mut self, width: i16) -> Self {
+        self.raw.outline_width = width;
+        self
+    }
+
+    pub fn outline_opa(mut self, opa: Opacity) -> Self {
+        self.raw.outline_opa = opa.into();
+        self
+    }
+
+    pub fn shadow_color(mut self, color: Color) -> Self {
+        self.raw.shadow_color = color.raw;
+        self
+    }
+
+    pub fn shadow_width(mut self, width: i16) -> Self {
+        self.raw.shadow_width = width;
+        self
+    }
+
+    pub fn shadow_opa(mut self, opa: Opacity) -> Self {
+        self.raw.shadow_opa = opa.into();
+        self
+    }
+}
+
+impl Default for DrawRect {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// How to draw a line or an arc.
+#[derive(Clone)]
+pub struct DrawLine {
+    pub(crate) raw: lvgl_sys::lv_draw_line_dsc_t,
+}
+
+impl DrawLine {
+    pub fn new() -> Self {
+        let raw = unsafe {
+            let mut raw = MaybeUninit::<lvgl_sys::lv_draw_line_dsc_t>::uninit();
+            lvgl_sys::lv_draw_line_dsc_init(raw.as_mut_ptr());
+            raw.assume_init()
+        };
+        Self { raw }
+    }
+
+    pub fn color(mut self, color: Color) -> Self {
+        self.raw.color = color.raw;
+        self
+    }
+
+    pub fn width(mut self, width: i16) -> Self {
+        self.raw.width = width;
+        self
+    }
+
+    pub fn opa(mut self, opa: Opacity) -> Self {
+        self.raw.opa = opa.into();
+        self
+    }
+
+    /// Draw a dashed line, a zero `width` or `gap` draws a solid line.
+    pub fn dash(mut self, width: i16, gap: i16) -> Self {
+        self.raw.dash_width = width;
+        self.raw.dash_gap = gap;
+        self
+    }
+
+    /// Round the start and the end of the line.
+    pub fn rounded(mut self, rounded: bool) -> Self {
+        self.raw.set_round_start(rounded as u8);
+        self.raw.set_round_end(rounded as u8);
+        self
+    }
+}
+
+impl Default for DrawLine {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// How to draw a text.
+#[derive(Clone)]
+pub struct DrawLabel {
+    pub(crate) raw: lvgl_sys::lv_draw_label_dsc_t,
+}
+
+impl DrawLabel {
+    pub fn new() -> Self {
+        let raw = unsafe {
+            let mut raw = MaybeUninit::<lvgl_sys::lv_draw_label_dsc_t>::uninit();
+            lvgl_sys::lv_draw_label_dsc_init(raw.as_mut_ptr());
+            raw.assume_init()
+        };
+        Self { raw }
+    }
+
+    pub fn color(mut self, color: Color) -> Self {
+        self.raw.color = color.raw;
+        self
+    }
+
+    pub fn font(mut self, font: Font) -> Self {
+        self.raw.font = font.into();
+        self
+    }
+
+    pub fn opa(mut self, opa: Opacity) -> Self {
+        self.raw.opa = opa.into();
+        self
+    }
+
+    pub fn line_space(mut self, space: i16) -> Self {
+        self.raw.line_space = space;
+        self
+    }
+
+    pub fn letter_space(mut self, space: i16) -> Self {
+        self.raw.letter_space = space;
+        self
+    }
+}
+
+impl Default for DrawLabel {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// How to draw an image.
+#[derive(Clone)]
+pub struct DrawImage {
+    pub(crate) raw: lvgl_sys::lv_draw_img_dsc_t,
+}
+
+impl DrawImage {
+    pub fn new() -> Self {
+        let raw = unsafe {
+            let mut raw = MaybeUninit::<lvgl_sys::lv_draw_img_dsc_t>::uninit();
+            lvgl_sys::lv_draw_img_dsc_init(raw.as_mut_ptr());
+            raw.assume_init()
+        };
+        Self { raw }
+    }
+
+    pub fn opa(mut self, opa: Opacity) -> Self {
+        self.raw.opa = opa.into();
+        self
+    }
+
+    /// Rotation in 0.1 degrees, around the pivot.
+    pub fn angle(mut self, angle: u16) -> Self {
+        self.raw.angle = angle;
+        self
+    }
+
+    /// The point the image is rotated around, relative to the image.
+    pub fn pivot(mut self, pivot: Point) -> Self {
+        self.raw.pivot = pivot.into();
+        self
+    }
+
+    /// Zoom factor, 256 is the original size.
+    pub fn zoom(mut self, zoom: u16) -> Self {
+        self.raw.zoom = zoom;
+        self
+    }
+
+    /// Mix `color` into the image, with the given intensity.
+    pub fn recolor(mut self, color: Color, opa: Opacity) -> Self {
+        self.raw.recolor = color.raw;
+        self.raw.recolor_opa = opa.into();
+        self
+    }
+}
+
+impl Default for DrawImage {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn rect_builder_sets_fields() {
+        crate::lvgl_init();
+        let default = DrawRect::new();
+        let rect = DrawRect::new()
+            .radius(4)
+            .border_width(2)
+            .bg_opa(Opacity::OPA_50)
+            .shadow_width(3);
+        assert_eq!(rect.raw.radius, 4);
+        assert_eq!(rect.raw.border_width, 2);
+        assert_eq!(rect.raw.bg_opa, lvgl_sys::LV_OPA_50 as u8);
+        assert_eq!(rect.raw.shadow_width, 3);
+        assert_eq!(rect.raw.outline_width, default.raw.outline_width);
+    }
+
+    #[test]
+    fn line_builder_sets_fields() {
+        crate::lvgl_init();
+        let line = DrawLine::new().width(3).dash(4, 2).rounded(true);
+        assert_eq!(line.raw.width, 3);
+        assert_eq!((line.raw.dash_width, line.raw.dash_gap), (4, 2));
+        assert_eq!((line.raw.round_start(), line.raw.round_end()), (1, 1));
+
+        let line = line.rounded(false).opa(Opacity::OPA_TRANSP);
+        assert_eq!((line.raw.round_start(), line.raw.round_end()), (0, 0));
+        assert_eq!(line.raw.opa, lvgl_sys::LV_OPA_TRANSP as u8);
+    }
+
+    #[test]
+    fn label_builder_sets_fields() {
+        crate::lvgl_init();
+        let label = DrawLabel::new().line_space(5).letter_space(1);
+        assert_eq!(label.raw.line_space, 5);
+        assert_eq!(label.raw.letter_space, 1);
+    }
+
+    #[test]
+    fn image_builder_sets_fields() {
+        crate::lvgl_init();
+        assert_eq!(DrawImage::new().raw.zoom, 256);
+        let image = DrawImage::new()
+            .angle(450)
+            .zoom(512)
+            .pivot(Point::new(10, 20))
+            .recolor(Color::from_rgb((255, 0, 0)), Opacity::OPA_COVER);
+        assert_eq!(image.raw.angle, 450);
+        assert_eq!(image.raw.zoom, 512);
+        assert_eq!((image.raw.pivot.x, image.raw.pivot.y), (10, 20));
+        assert_eq!(image.raw.recolor_opa, lvgl_sys::LV_OPA_COVER as u8);
+    }
+}
@@ -0,0 +1,39 @@
+/// A font used by LVGL to render text.
+///
+/// LVGL only keeps a pointer to the font description, so fonts need to live for the whole
+/// program. Fonts are usually defined in C, either the built-in ones enabled in `lv_conf.h`
+/// (e.g. `lv_font_montserrat_16`) or fonts converted with the LVGL font converter.
+#[derive(Copy, Clone)]
+pub struct Font {
+    raw: &'static lvgl_sys::lv_font_t,
+}
+
+impl Font {
+    /// Use a native LVGL font.
+    ///
+    /// ```ignore
+    /// extern "C" {
+    ///     static noto_sans_numeric_80: lvgl_sys::lv_font_t;
+    /// }
+    ///
+    /// let font = Font::from_raw(unsafe { &noto_sans_numeric_80 });
+    /// ```
+    pub fn from_raw(raw: &'static lvgl_sys::lv_font_t) -> Self {
+        Self { raw }
+    }
+
+    /// The line height of the font in pixels.
+    pub fn line_height(&self) -> i16 {
+        self.raw.line_height
+    }
+
+    pub(crate) fn raw(&self) -> *const lvgl_sys::lv_font_t {
+        self.raw as *const _
+    }
+}
+
+impl From<Font> for *const lvgl_sys::lv_font_t {
+    fn from(font: Font) -> Self {
+        font.raw()
+    }
+}
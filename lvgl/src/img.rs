@@ -0,0 +1,224 @@
+use crate::{LvError, LvResult};
+use cstr_core::CStr;
+
+/// The source of an image.
+///
+/// LVGL only keeps a pointer to the image source, so it needs to live for the whole program.
+#[derive(Copy, Clone)]
+pub enum ImageSource {
+    /// An image in memory, e.g. converted from a PNG file.
+    Descriptor(&'static ImageDescriptor),
+    /// A file path in a registered file system driver, e.g. `S:folder/image.bin`.
+    File(&'static CStr),
+    /// A symbol of the built-in symbols font, e.g. `LV_SYMBOL_OK`.
+    Symbol(&'static CStr),
+}
+
+impl ImageSource {
+    /// A built-in symbol, e.g. `ImageSource::symbol(lvgl_sys::LV_SYMBOL_OK)`.
+    pub fn symbol(symbol: &'static [u8]) -> LvResult<Self> {
+        CStr::from_bytes_with_nul(symbol)
+            .map(ImageSource::Symbol)
+            .map_err(|_| LvError::InvalidReference)
+    }
+
+    pub(crate) fn as_ptr(&self) -> *const cty::c_void {
+        match self {
+            ImageSource::Descriptor(dsc) => &dsc.raw as *const _ as *const cty::c_void,
+            ImageSource::File(path) => path.as_ptr() as *const cty::c_void,
+            ImageSource::Symbol(symbol) => symbol.as_ptr() as *const cty::c_void,
+        }
+    }
+}
+
+/// An image in memory, the pixels in one of the LVGL color formats, after the palette for
+/// indexed images. The pixel data of true color images must match `LV_COLOR_DEPTH`.
+///
+/// ```ignore
+/// static LOGO_DATA: [u8; 32 * 32 * 2] = [/* ... */];
+/// static LOGO: ImageDescriptor = ImageDescriptor::new(&LOGO_DATA, 32, 32, ColorFormat::TrueColor);
+///
+/// img.set_src(ImageSource::Descriptor(&LOGO))?;
+/// ```
+///
+/// With the `macros` feature, `include_lv_img!` converts an image file to a descriptor.
+#[repr(transparent)]
+pub struct ImageDescriptor {
+    raw: lvgl_sys::lv_img_dsc_t,
+}
+
+// The data is immutable and lives for the whole program.
+unsafe impl Sync for ImageDescriptor {}
+
+impl ImageDescriptor {
+    /// Describe the image data of `width` by `height` pixels, at most 2047 pixels each.
+    ///
+    /// Panics if `data` is smaller than the size of the image in `format`.
+    pub const fn new(data: &'static [u8], width: u16, height: u16, format: ColorFormat) -> Self {
+        assert!(width < 1 << 11 && height < 1 << 11, "image too large");
+        assert!(
+            data.len() >= format.buffer_size(width, height),
+            "not enough image data"
+        );
+        Self {
+            raw: lvgl_sys::lv_img_dsc_t {
+                header: header(format as u32, width as u32, height as u32),
+                data_size: data.len() as u32,
+                data: data.as_ptr(),
+            },
+        }
+    }
+
+    pub fn width(&self) -> u16 {
+        self.raw.header.w() as u16
+    }
+
+    pub fn height(&self) -> u16 {
+        self.raw.header.h() as u16
+    }
+
+    pub fn data(&self) -> &'static [u8] {
+        unsafe { core::slice::from_raw_parts(self.raw.data, self.raw.data_size as usize) }
+    }
+}
+
+// `lv_img_header_t` is a 32 bits bit field, of `cf:5`, `always_zero:3`, `reserved:2`, `w:11`
+// and `h:11`. Bit fields are laid out from the least significant bit on little endian targets
+// and from the most significant bit on big endian targets.
+const fn header(cf: u32, w: u32, h: u32) -> lvgl_sys::lv_img_header_t {
+    let bits = if cfg!(target_endian = "little") {
+        cf | w << 10 | h << 21
+    } else {
+        cf << 27 | w << 11 | h
+    };
+    unsafe { core::mem::transmute::<u32, lvgl_sys::lv_img_header_t>(bits) }
+}
+
+/// How the pixels of an image are stored.
+#[derive(Debug, Copy, Clone, PartialEq)]
+#[repr(u8)]
+pub enum ColorFormat {
+    /// Colors of `LV_COLOR_DEPTH`
+    TrueColor = lvgl_sys::LV_IMG_CF_TRUE_COLOR as u8,
+    /// Colors of `LV_COLOR_DEPTH` followed by an alpha byte
+    TrueColorAlpha = lvgl_sys::LV_IMG_CF_TRUE_COLOR_ALPHA as u8,
+    /// Colors of `LV_COLOR_DEPTH`, `LV_COLOR_TRANSP` pixels are transparent
+    TrueColorChromaKeyed = lvgl_sys::LV_IMG_CF_TRUE_COLOR_CHROMA_KEYED as u8,
+    /// Indices in a palette of 2 colors
+    Indexed1Bit = lvgl_sys::LV_IMG_CF_INDEXED_1BIT as u8,
+    /// Indices in a palette of 4 colors
+    Indexed2Bit = lvgl_sys::LV_IMG_CF_INDEXED_2BIT as u8,
+    /// Indices in a palette of 16 colors
+    Indexed4Bit = lvgl_sys::LV_IMG_CF_INDEXED_4BIT as u8,
+    /// Indices in a palette of 256 colors
+    Indexed8Bit = lvgl_sys::LV_IMG_CF_INDEXED_8BIT as u8,
+    /// Only the opacity, with 1 bit for each pixel
+    Alpha1Bit = lvgl_sys::LV_IMG_CF_ALPHA_1BIT as u8,
+    /// Only the opacity, with 2 bits for each pixel
+    Alpha2Bit = lvgl_sys::LV_IMG_CF_ALPHA_2BIT as u8,
+    /// Only the opacity, with 4 bits for each pixel
+    Alpha4Bit = lvgl_sys::LV_IMG_CF_ALPHA_4BIT as u8,
+    /// Only the opacity, with 8 bits for each pixel
+    Alpha8Bit = lvgl_sys::LV_IMG_CF_ALPHA_8BIT as u8,
+}
+
+impl ColorFormat {
+    /// The size in bytes of an image of `width` by `height` pixels, as read by LVGL: with less
+    /// than a byte per pixel, each row starts on a new byte. `lv_img_buf_get_img_size` can be
+    /// larger, it adds a byte to each row of these formats instead of rounding up.
+    pub const fn buffer_size(self, width: u16, height: u16) -> usize {
+        let (w, h) = (width as usize, height as usize);
+        let color_size = core::mem::size_of::<lvgl_sys::lv_color_t>();
+        match self {
+            ColorFormat::TrueColor | ColorFormat::TrueColorChromaKeyed => w * h * color_size,
+            // `LV_IMG_PX_SIZE_ALPHA_BYTE`, 32 bits colors have an alpha channel already
+            ColorFormat::TrueColorAlpha if color_size == 4 => w * h * 4,
+            ColorFormat::TrueColorAlpha => w * h * (color_size + 1),
+            ColorFormat::Alpha1Bit => (w + 7) / 8 * h,
+            ColorFormat::Alpha2Bit => (w + 3) / 4 * h,
+            ColorFormat::Alpha4Bit => (w + 1) / 2 * h,
+            ColorFormat::Alpha8Bit => w * h,
+            // The pixels follow a palette of 32 bits colors
+            ColorFormat::Indexed1Bit => (w + 7) / 8 * h + 4 * 2,
+            ColorFormat::Indexed2Bit => (w + 3) / 4 * h + 4 * 4,
+            ColorFormat::Indexed4Bit => (w + 1) / 2 * h + 4 * 16,
+            ColorFormat::Indexed8Bit => w * h + 4 * 256,
+        }
+    }
+
+    /// Convert RGBA pixels, 4 bytes each, to the `N` bytes of a true color image of
+    /// `LV_COLOR_DEPTH`. Used by `include_lv_img!` to convert images when building.
+    #[doc(hidden)]
+    pub const fn from_rgba<const N: usize>(self, rgba: &[u8]) -> [u8; N] {
+        let color_size = core::mem::size_of::<lvgl_sys::lv_color_t>();
+        let alpha = matches!(self, ColorFormat::TrueColorAlpha);
+        let px_size = if alpha && color_size != 4 {
+            color_size + 1
+        } else {
+            color_size
+        };
+        assert!(
+            matches!(
+                self,
+                ColorFormat::TrueColor
+                    | ColorFormat::TrueColorAlpha
+                    | ColorFormat::TrueColorChromaKeyed
+            ),
+            "not a true color format"
+        );
+        assert!(
+            rgba.len() % 4 == 0 && rgba.len() / 4 * px_size == N,
+            "the size of the image does not match"
+        );
+
+        let mut data = [0; N];
+        let mut i = 0;
+        while i < rgba.len() / 4 {
+            let (mut r, mut g, mut b, a) = (
+                rgba[i * 4],
+                rgba[i * 4 + 1],
+                rgba[i * 4 + 2],
+                rgba[i * 4 + 3],
+            );
+            if matches!(self, ColorFormat::TrueColorChromaKeyed) && a < 0x80 {
+                // `LV_COLOR_TRANSP`, pure green by default
+                r = 0x00;
+                g = 0xff;
+                b = 0x00;
+            }
+            let px = i * px_size;
+            match lvgl_sys::LV_COLOR_DEPTH {
+                32 => {
+                    data[px] = b;
+                    data[px + 1] = g;
+                    data[px + 2] = r;
+                    data[px + 3] = if alpha { a } else { 0xff };
+                }
+                16 => {
+                    let color = (r as u16 >> 3) << 11 | (g as u16 >> 2) << 5 | b as u16 >> 3;
+                    // With `LV_COLOR_16_SWAP` the bytes are in the order of the display
+                    let bytes = if lvgl_sys::LV_COLOR_16_SWAP != 0 {
+                        color.to_be_bytes()
+                    } else {
+                        color.to_ne_bytes()
+                    };
+                    data[px] = bytes[0];
+                    data[px + 1] = bytes[1];
+                }
+                8 => data[px] = r & 0xe0 | (g >> 5) << 2 | b >> 6,
+                _ => data[px] = (r | g | b) >> 7,
+            }
+            if alpha && color_size != 4 {
+                data[px + color_size] = a;
+            }
+            i += 1;
+        }
+        data
+    }
+}
+
+impl From<ColorFormat> for lvgl_sys::lv_img_cf_t {
+    fn from(format: ColorFormat) -> Self {
+        format as lvgl_sys::lv_img_cf_t
+    }
+}
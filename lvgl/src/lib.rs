@@ -17,7 +17,7 @@
 #[macro_use]
 extern crate bitflags;
 
-#[cfg(feature = "lvgl_alloc")]
+#[cfg(feature = "alloc")]
 extern crate alloc;
 
 // We can ONLY use `alloc::boxed::Box` if `lvgl_alloc` is enabled.
@@ -31,6 +31,11 @@ use ::alloc::boxed::Box;
 #[cfg(feature = "lvgl_alloc")]
 mod allocator;
 
+mod anim;
+pub mod draw;
+mod font;
+mod img;
+mod modules;
 mod support;
 mod ui;
 #[macro_use]
@@ -47,10 +52,17 @@ pub(crate) mod mem;
 #[cfg(not(feature = "lvgl_alloc"))]
 use crate::mem::Box;
 
+pub use anim::*;
+pub use font::*;
+pub use img::*;
 pub use lv_core::*;
+pub use modules::*;
 pub use support::*;
 pub use ui::*;
 
+#[cfg(feature = "macros")]
+pub use lvgl_macros::include_lv_img;
+
 use core::sync::atomic::{AtomicBool, Ordering};
 
 // Initialize LVGL only once.
@@ -1,5 +1,6 @@
 #[macro_use]
 mod obj;
 pub mod style;
+pub mod theme;
 
 pub use obj::*;
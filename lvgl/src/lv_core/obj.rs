@@ -1,6 +1,5 @@
 use crate::lv_core::style::Style;
-use crate::Box;
-use crate::{Align, LvError, LvResult};
+use crate::{Align, LvError, LvResult, WidgetEvent};
 use core::ptr;
 
 /// Represents a native LVGL object
@@ -28,9 +27,12 @@ impl NativeObject for Obj {
     }
 }
 
+// Defines `widget_methods!`, with the `lv_obj_*` setters and getters
+include!(concat!(env!("OUT_DIR"), "/generated_obj.rs"));
+
 /// A wrapper for all LVGL common operations on generic objects.
 pub trait Widget: NativeObject {
-    type SpecialEvent;
+    type SpecialEvent: WidgetEvent;
     type Part: Into<u8>;
 
     /// Construct an instance of the object from a raw pointer.
@@ -42,7 +44,7 @@ pub trait Widget: NativeObject {
 
     fn add_style(&self, part: Self::Part, style: Style) -> LvResult<()> {
         unsafe {
-            lvgl_sys::lv_obj_add_style(self.raw()?.as_mut(), part.into(), Box::into_raw(style.raw));
+            lvgl_sys::lv_obj_add_style(self.raw()?.as_mut(), part.into(), style.into_raw());
         };
         Ok(())
     }
@@ -98,6 +100,8 @@ pub trait Widget: NativeObject {
         }
         Ok(())
     }
+
+    widget_methods!();
 }
 
 impl Widget for Obj {
@@ -140,7 +144,8 @@ macro_rules! define_object {
         impl $item {
             pub fn on_event<F>(&mut self, f: F) -> $crate::LvResult<()>
             where
-                F: FnMut(Self, $crate::support::Event<<Self as $crate::Widget>::SpecialEvent>),
+                F: FnMut(Self, $crate::support::Event<<Self as $crate::Widget>::SpecialEvent>)
+                    + 'static,
             {
                 use $crate::NativeObject;
                 unsafe {
@@ -1,1206 +1,454 @@
 use crate::Box;
-use crate::{Color, LvResult, State};
+use crate::{AnimPath, Color, Font, ImageSource, LvError, LvResult, State};
 use core::mem;
+use core::ptr::{self, NonNull};
+use core::time::Duration;
 use cstr_core::CStr;
 
-pub enum Themes {
-    Pretty,
-}
-
 pub struct Style {
     pub(crate) raw: Box<lvgl_sys::lv_style_t>,
+    // LVGL only stores pointers for some of the properties, the values they point to are owned
+    // by the style and released when it is dropped.
+    owned: Option<Box<OwnedValue>>,
 }
 
 impl Style {
-    pub fn set_value_str(&mut self, state: State, value: &CStr) -> LvResult<()> {
-        let native_state: u32 = state.get_bits();
-        unsafe {
-            lvgl_sys::_lv_style_set_ptr(
-                self.raw.as_mut(),
-                (lvgl_sys::LV_STYLE_VALUE_STR
-                    | (native_state << lvgl_sys::LV_STYLE_STATE_POS as u32)) as u16,
-                value.as_ptr() as *mut cty::c_void,
-            );
-        }
-        Ok(())
-    }
-}
-
-impl Default for Style {
-    fn default() -> Self {
-        let raw = unsafe {
-            let mut style = mem::MaybeUninit::<lvgl_sys::lv_style_t>::uninit();
-            lvgl_sys::lv_style_init(style.as_mut_ptr());
-            Box::new(style.assume_init())
-        };
-        Self { raw }
-    }
-}
-
-bitflags! {
-    pub struct Opacity: u32 {
-        const OPA_TRANSP = lvgl_sys::LV_OPA_TRANSP;
-        const OPA_0 = lvgl_sys::LV_OPA_0;
-        const OPA_10 = lvgl_sys::LV_OPA_10;
-        const OPA_20 = lvgl_sys::LV_OPA_20;
-        const OPA_30 = lvgl_sys::LV_OPA_30;
-        const OPA_40 = lvgl_sys::LV_OPA_40;
-        const OPA_50 = lvgl_sys::LV_OPA_50;
-        const OPA_60 = lvgl_sys::LV_OPA_60;
-        const OPA_70 = lvgl_sys::LV_OPA_70;
-        const OPA_80 = lvgl_sys::LV_OPA_80;
-        const OPA_90 = lvgl_sys::LV_OPA_90;
-        const OPA_100 = lvgl_sys::LV_OPA_100;
-        const OPA_COVER = lvgl_sys::LV_OPA_COVER;
-    }
-}
-
-impl Into<u8> for Opacity {
-    fn into(self) -> u8 {
-        self.bits as u8
-    }
-}
-
-bitflags! {
-    pub struct StyleProp: u32 {
-        const RADIUS = lvgl_sys::LV_STYLE_RADIUS;
-        const CLIP_CORNER = lvgl_sys::LV_STYLE_CLIP_CORNER;
-        const SIZE = lvgl_sys::LV_STYLE_SIZE;
-        const TRANSFORM_WIDTH = lvgl_sys::LV_STYLE_TRANSFORM_WIDTH;
-        const TRANSFORM_HEIGHT = lvgl_sys::LV_STYLE_TRANSFORM_HEIGHT;
-        const TRANSFORM_ANGLE = lvgl_sys::LV_STYLE_TRANSFORM_ANGLE;
-        const TRANSFORM_ZOOM = lvgl_sys::LV_STYLE_TRANSFORM_ZOOM;
-        const OPA_SCALE = lvgl_sys::LV_STYLE_OPA_SCALE;
-        const PAD_TOP = lvgl_sys::LV_STYLE_PAD_TOP;
-        const PAD_BOTTOM = lvgl_sys::LV_STYLE_PAD_BOTTOM;
-        const PAD_LEFT = lvgl_sys::LV_STYLE_PAD_LEFT;
-        const PAD_RIGHT = lvgl_sys::LV_STYLE_PAD_RIGHT;
-        const PAD_INNER = lvgl_sys::LV_STYLE_PAD_INNER;
-        const MARGIN_TOP = lvgl_sys::LV_STYLE_MARGIN_TOP;
-        const MARGIN_BOTTOM = lvgl_sys::LV_STYLE_MARGIN_BOTTOM;
-        const MARGIN_LEFT = lvgl_sys::LV_STYLE_MARGIN_LEFT;
-        const MARGIN_RIGHT = lvgl_sys::LV_STYLE_MARGIN_RIGHT;
-        const BG_BLEND_MODE = lvgl_sys::LV_STYLE_BG_BLEND_MODE;
-        const BG_MAIN_STOP = lvgl_sys::LV_STYLE_BG_MAIN_STOP;
-        const BG_GRAD_STOP = lvgl_sys::LV_STYLE_BG_GRAD_STOP;
-        const BG_GRAD_DIR = lvgl_sys::LV_STYLE_BG_GRAD_DIR;
-        const BG_COLOR = lvgl_sys::LV_STYLE_BG_COLOR;
-        const BG_GRAD_COLOR = lvgl_sys::LV_STYLE_BG_GRAD_COLOR;
-        const BG_OPA = lvgl_sys::LV_STYLE_BG_OPA;
-        const BORDER_WIDTH = lvgl_sys::LV_STYLE_BORDER_WIDTH;
-        const BORDER_SIDE = lvgl_sys::LV_STYLE_BORDER_SIDE;
-        const BORDER_BLEND_MODE = lvgl_sys::LV_STYLE_BORDER_BLEND_MODE;
-        const BORDER_POST = lvgl_sys::LV_STYLE_BORDER_POST;
-        const BORDER_COLOR = lvgl_sys::LV_STYLE_BORDER_COLOR;
-        const BORDER_OPA = lvgl_sys::LV_STYLE_BORDER_OPA;
-        const OUTLINE_WIDTH = lvgl_sys::LV_STYLE_OUTLINE_WIDTH;
-        const OUTLINE_PAD = lvgl_sys::LV_STYLE_OUTLINE_PAD;
-        const OUTLINE_BLEND_MODE = lvgl_sys::LV_STYLE_OUTLINE_BLEND_MODE;
-        const OUTLINE_COLOR = lvgl_sys::LV_STYLE_OUTLINE_COLOR;
-        const OUTLINE_OPA = lvgl_sys::LV_STYLE_OUTLINE_OPA;
-        const SHADOW_WIDTH = lvgl_sys::LV_STYLE_SHADOW_WIDTH;
-        const SHADOW_OFS_X = lvgl_sys::LV_STYLE_SHADOW_OFS_X;
-        const SHADOW_OFS_Y = lvgl_sys::LV_STYLE_SHADOW_OFS_Y;
-        const SHADOW_SPREAD = lvgl_sys::LV_STYLE_SHADOW_SPREAD;
-        const SHADOW_BLEND_MODE = lvgl_sys::LV_STYLE_SHADOW_BLEND_MODE;
-        const SHADOW_COLOR = lvgl_sys::LV_STYLE_SHADOW_COLOR;
-        const SHADOW_OPA = lvgl_sys::LV_STYLE_SHADOW_OPA;
-        const PATTERN_BLEND_MODE = lvgl_sys::LV_STYLE_PATTERN_BLEND_MODE;
-        const PATTERN_REPEAT = lvgl_sys::LV_STYLE_PATTERN_REPEAT;
-        const PATTERN_RECOLOR = lvgl_sys::LV_STYLE_PATTERN_RECOLOR;
-        const PATTERN_OPA = lvgl_sys::LV_STYLE_PATTERN_OPA;
-        const PATTERN_RECOLOR_OPA = lvgl_sys::LV_STYLE_PATTERN_RECOLOR_OPA;
-        const PATTERN_IMAGE = lvgl_sys::LV_STYLE_PATTERN_IMAGE;
-        const VALUE_LETTER_SPACE = lvgl_sys::LV_STYLE_VALUE_LETTER_SPACE;
-        const VALUE_LINE_SPACE = lvgl_sys::LV_STYLE_VALUE_LINE_SPACE;
-        const VALUE_BLEND_MODE = lvgl_sys::LV_STYLE_VALUE_BLEND_MODE;
-        const VALUE_OFS_X = lvgl_sys::LV_STYLE_VALUE_OFS_X;
-        const VALUE_OFS_Y = lvgl_sys::LV_STYLE_VALUE_OFS_Y;
-        const VALUE_ALIGN = lvgl_sys::LV_STYLE_VALUE_ALIGN;
-        const VALUE_COLOR = lvgl_sys::LV_STYLE_VALUE_COLOR;
-        const VALUE_OPA = lvgl_sys::LV_STYLE_VALUE_OPA;
-        const VALUE_FONT = lvgl_sys::LV_STYLE_VALUE_FONT;
-        const VALUE_STR = lvgl_sys::LV_STYLE_VALUE_STR;
-        const TEXT_LETTER_SPACE = lvgl_sys::LV_STYLE_TEXT_LETTER_SPACE;
-        const TEXT_LINE_SPACE = lvgl_sys::LV_STYLE_TEXT_LINE_SPACE;
-        const TEXT_DECOR = lvgl_sys::LV_STYLE_TEXT_DECOR;
-        const TEXT_BLEND_MODE = lvgl_sys::LV_STYLE_TEXT_BLEND_MODE;
-        const TEXT_COLOR = lvgl_sys::LV_STYLE_TEXT_COLOR;
-        const TEXT_SEL_COLOR = lvgl_sys::LV_STYLE_TEXT_SEL_COLOR;
-        const TEXT_OPA = lvgl_sys::LV_STYLE_TEXT_OPA;
-        const TEXT_FONT = lvgl_sys::LV_STYLE_TEXT_FONT;
-        const LINE_WIDTH = lvgl_sys::LV_STYLE_LINE_WIDTH;
-        const LINE_BLEND_MODE = lvgl_sys::LV_STYLE_LINE_BLEND_MODE;
-        const LINE_DASH_WIDTH = lvgl_sys::LV_STYLE_LINE_DASH_WIDTH;
-        const LINE_DASH_GAP = lvgl_sys::LV_STYLE_LINE_DASH_GAP;
-        const LINE_ROUNDED = lvgl_sys::LV_STYLE_LINE_ROUNDED;
-        const LINE_COLOR = lvgl_sys::LV_STYLE_LINE_COLOR;
-        const LINE_OPA = lvgl_sys::LV_STYLE_LINE_OPA;
-        const IMAGE_BLEND_MODE = lvgl_sys::LV_STYLE_IMAGE_BLEND_MODE;
-        const IMAGE_RECOLOR = lvgl_sys::LV_STYLE_IMAGE_RECOLOR;
-        const IMAGE_OPA = lvgl_sys::LV_STYLE_IMAGE_OPA;
-        const IMAGE_RECOLOR_OPA = lvgl_sys::LV_STYLE_IMAGE_RECOLOR_OPA;
-        const TRANSITION_TIME = lvgl_sys::LV_STYLE_TRANSITION_TIME;
-        const TRANSITION_DELAY = lvgl_sys::LV_STYLE_TRANSITION_DELAY;
-        const TRANSITION_PROP_1 = lvgl_sys::LV_STYLE_TRANSITION_PROP_1;
-        const TRANSITION_PROP_2 = lvgl_sys::LV_STYLE_TRANSITION_PROP_2;
-        const TRANSITION_PROP_3 = lvgl_sys::LV_STYLE_TRANSITION_PROP_3;
-        const TRANSITION_PROP_4 = lvgl_sys::LV_STYLE_TRANSITION_PROP_4;
-        const TRANSITION_PROP_5 = lvgl_sys::LV_STYLE_TRANSITION_PROP_5;
-        const TRANSITION_PROP_6 = lvgl_sys::LV_STYLE_TRANSITION_PROP_6;
-        const TRANSITION_PATH = lvgl_sys::LV_STYLE_TRANSITION_PATH;
-        const SCALE_WIDTH = lvgl_sys::LV_STYLE_SCALE_WIDTH;
-        const SCALE_BORDER_WIDTH = lvgl_sys::LV_STYLE_SCALE_BORDER_WIDTH;
-        const SCALE_END_BORDER_WIDTH = lvgl_sys::LV_STYLE_SCALE_END_BORDER_WIDTH;
-        const SCALE_END_LINE_WIDTH = lvgl_sys::LV_STYLE_SCALE_END_LINE_WIDTH;
-        const SCALE_GRAD_COLOR = lvgl_sys::LV_STYLE_SCALE_GRAD_COLOR;
-        const SCALE_END_COLOR = lvgl_sys::LV_STYLE_SCALE_END_COLOR;
-    }
-}
-
-// Auto-gen code, please look into lvgl-codegen for any changes.
-impl Style {
-    pub fn set_radius(&mut self, state: State, value: i16) {
-        let native_state: u32 = state.get_bits();
-        unsafe {
-            lvgl_sys::_lv_style_set_int(
-                self.raw.as_mut(),
-                (lvgl_sys::LV_STYLE_RADIUS | (native_state << lvgl_sys::LV_STYLE_STATE_POS as u32))
-                    as u16,
-                value,
-            );
-        }
-    }
-
-    pub fn set_clip_corner(&mut self, state: State, value: i16) {
-        let native_state: u32 = state.get_bits();
-        unsafe {
-            lvgl_sys::_lv_style_set_int(
-                self.raw.as_mut(),
-                (lvgl_sys::LV_STYLE_CLIP_CORNER
-                    | (native_state << lvgl_sys::LV_STYLE_STATE_POS as u32)) as u16,
-                value,
-            );
-        }
-    }
-
-    pub fn set_size(&mut self, state: State, value: i16) {
-        let native_state: u32 = state.get_bits();
-        unsafe {
-            lvgl_sys::_lv_style_set_int(
-                self.raw.as_mut(),
-                (lvgl_sys::LV_STYLE_SIZE | (native_state << lvgl_sys::LV_STYLE_STATE_POS as u32))
-                    as u16,
-                value,
-            );
-        }
-    }
-
-    pub fn set_transform_width(&mut self, state: State, value: i16) {
-        let native_state: u32 = state.get_bits();
-        unsafe {
-            lvgl_sys::_lv_style_set_int(
-                self.raw.as_mut(),
-                (lvgl_sys::LV_STYLE_TRANSFORM_WIDTH
-                    | (native_state << lvgl_sys::LV_STYLE_STATE_POS as u32)) as u16,
-                value,
-            );
-        }
-    }
-
-    pub fn set_transform_height(&mut self, state: State, value: i16) {
-        let native_state: u32 = state.get_bits();
-        unsafe {
-            lvgl_sys::_lv_style_set_int(
-                self.raw.as_mut(),
-                (lvgl_sys::LV_STYLE_TRANSFORM_HEIGHT
-                    | (native_state << lvgl_sys::LV_STYLE_STATE_POS as u32)) as u16,
-                value,
-            );
-        }
-    }
-
-    pub fn set_transform_angle(&mut self, state: State, value: i16) {
-        let native_state: u32 = state.get_bits();
-        unsafe {
-            lvgl_sys::_lv_style_set_int(
-                self.raw.as_mut(),
-                (lvgl_sys::LV_STYLE_TRANSFORM_ANGLE
-                    | (native_state << lvgl_sys::LV_STYLE_STATE_POS as u32)) as u16,
-                value,
-            );
-        }
-    }
-
-    pub fn set_transform_zoom(&mut self, state: State, value: i16) {
-        let native_state: u32 = state.get_bits();
-        unsafe {
-            lvgl_sys::_lv_style_set_int(
-                self.raw.as_mut(),
-                (lvgl_sys::LV_STYLE_TRANSFORM_ZOOM
-                    | (native_state << lvgl_sys::LV_STYLE_STATE_POS as u32)) as u16,
-                value,
-            );
-        }
-    }
-
-    pub fn set_opa_scale(&mut self, state: State, value: Opacity) {
-        let native_state: u32 = state.get_bits();
-        unsafe {
-            lvgl_sys::_lv_style_set_opa(
-                self.raw.as_mut(),
-                (lvgl_sys::LV_STYLE_OPA_SCALE
-                    | (native_state << lvgl_sys::LV_STYLE_STATE_POS as u32)) as u16,
-                value.into(),
-            );
-        }
-    }
-
-    pub fn set_pad_top(&mut self, state: State, value: i16) {
-        let native_state: u32 = state.get_bits();
-        unsafe {
-            lvgl_sys::_lv_style_set_int(
-                self.raw.as_mut(),
-                (lvgl_sys::LV_STYLE_PAD_TOP | (native_state << lvgl_sys::LV_STYLE_STATE_POS as u32))
-                    as u16,
-                value,
-            );
-        }
-    }
-
-    pub fn set_pad_bottom(&mut self, state: State, value: i16) {
-        let native_state: u32 = state.get_bits();
-        unsafe {
-            lvgl_sys::_lv_style_set_int(
-                self.raw.as_mut(),
-                (lvgl_sys::LV_STYLE_PAD_BOTTOM
-                    | (native_state << lvgl_sys::LV_STYLE_STATE_POS as u32)) as u16,
-                value,
-            );
-        }
-    }
-
-    pub fn set_pad_left(&mut self, state: State, value: i16) {
-        let native_state: u32 = state.get_bits();
-        unsafe {
-            lvgl_sys::_lv_style_set_int(
-                self.raw.as_mut(),
-                (lvgl_sys::LV_STYLE_PAD_LEFT
-                    | (native_state << lvgl_sys::LV_STYLE_STATE_POS as u32)) as u16,
-                value,
-            );
-        }
-    }
-
-    pub fn set_pad_right(&mut self, state: State, value: i16) {
-        let native_state: u32 = state.get_bits();
-        unsafe {
-            lvgl_sys::_lv_style_set_int(
-                self.raw.as_mut(),
-                (lvgl_sys::LV_STYLE_PAD_RIGHT
-                    | (native_state << lvgl_sys::LV_STYLE_STATE_POS as u32)) as u16,
-                value,
-            );
-        }
-    }
-
-    pub fn set_pad_inner(&mut self, state: State, value: i16) {
-        let native_state: u32 = state.get_bits();
-        unsafe {
-            lvgl_sys::_lv_style_set_int(
-                self.raw.as_mut(),
-                (lvgl_sys::LV_STYLE_PAD_INNER
-                    | (native_state << lvgl_sys::LV_STYLE_STATE_POS as u32)) as u16,
-                value,
-            );
-        }
-    }
-
-    pub fn set_margin_top(&mut self, state: State, value: i16) {
-        let native_state: u32 = state.get_bits();
-        unsafe {
-            lvgl_sys::_lv_style_set_int(
-                self.raw.as_mut(),
-                (lvgl_sys::LV_STYLE_MARGIN_TOP
-                    | (native_state << lvgl_sys::LV_STYLE_STATE_POS as u32)) as u16,
-                value,
-            );
-        }
-    }
-
-    pub fn set_margin_bottom(&mut self, state: State, value: i16) {
-        let native_state: u32 = state.get_bits();
-        unsafe {
-            lvgl_sys::_lv_style_set_int(
-                self.raw.as_mut(),
-                (lvgl_sys::LV_STYLE_MARGIN_BOTTOM
-                    | (native_state << lvgl_sys::LV_STYLE_STATE_POS as u32)) as u16,
-                value,
-            );
-        }
-    }
-
-    pub fn set_margin_left(&mut self, state: State, value: i16) {
-        let native_state: u32 = state.get_bits();
-        unsafe {
-            lvgl_sys::_lv_style_set_int(
-                self.raw.as_mut(),
-                (lvgl_sys::LV_STYLE_MARGIN_LEFT
-                    | (native_state << lvgl_sys::LV_STYLE_STATE_POS as u32)) as u16,
-                value,
-            );
-        }
-    }
-
-    pub fn set_margin_right(&mut self, state: State, value: i16) {
-        let native_state: u32 = state.get_bits();
-        unsafe {
-            lvgl_sys::_lv_style_set_int(
-                self.raw.as_mut(),
-                (lvgl_sys::LV_STYLE_MARGIN_RIGHT
-                    | (native_state << lvgl_sys::LV_STYLE_STATE_POS as u32)) as u16,
-                value,
-            );
-        }
-    }
-
-    pub fn set_bg_blend_mode(&mut self, state: State, value: i16) {
-        let native_state: u32 = state.get_bits();
-        unsafe {
-            lvgl_sys::_lv_style_set_int(
-                self.raw.as_mut(),
-                (lvgl_sys::LV_STYLE_BG_BLEND_MODE
-                    | (native_state << lvgl_sys::LV_STYLE_STATE_POS as u32)) as u16,
-                value,
-            );
-        }
-    }
-
-    pub fn set_bg_main_stop(&mut self, state: State, value: i16) {
-        let native_state: u32 = state.get_bits();
-        unsafe {
-            lvgl_sys::_lv_style_set_int(
-                self.raw.as_mut(),
-                (lvgl_sys::LV_STYLE_BG_MAIN_STOP
-                    | (native_state << lvgl_sys::LV_STYLE_STATE_POS as u32)) as u16,
-                value,
-            );
-        }
-    }
-
-    pub fn set_bg_grad_stop(&mut self, state: State, value: i16) {
-        let native_state: u32 = state.get_bits();
-        unsafe {
-            lvgl_sys::_lv_style_set_int(
-                self.raw.as_mut(),
-                (lvgl_sys::LV_STYLE_BG_GRAD_STOP
-                    | (native_state << lvgl_sys::LV_STYLE_STATE_POS as u32)) as u16,
-                value,
-            );
-        }
-    }
-
-    pub fn set_bg_grad_dir(&mut self, state: State, value: i16) {
-        let native_state: u32 = state.get_bits();
-        unsafe {
-            lvgl_sys::_lv_style_set_int(
-                self.raw.as_mut(),
-                (lvgl_sys::LV_STYLE_BG_GRAD_DIR
-                    | (native_state << lvgl_sys::LV_STYLE_STATE_POS as u32)) as u16,
-                value,
-            );
-        }
-    }
-
-    pub fn set_bg_color(&mut self, state: State, value: Color) {
-        let native_state: u32 = state.get_bits();
-        unsafe {
-            lvgl_sys::_lv_style_set_color(
-                self.raw.as_mut(),
-                (lvgl_sys::LV_STYLE_BG_COLOR
-                    | (native_state << lvgl_sys::LV_STYLE_STATE_POS as u32)) as u16,
-                value.raw,
-            );
-        }
-    }
-
-    pub fn set_bg_grad_color(&mut self, state: State, value: Color) {
-        let native_state: u32 = state.get_bits();
-        unsafe {
-            lvgl_sys::_lv_style_set_color(
-                self.raw.as_mut(),
-                (lvgl_sys::LV_STYLE_BG_GRAD_COLOR
-                    | (native_state << lvgl_sys::LV_STYLE_STATE_POS as u32)) as u16,
-                value.raw,
-            );
-        }
-    }
-
-    pub fn set_bg_opa(&mut self, state: State, value: Opacity) {
-        let native_state: u32 = state.get_bits();
-        unsafe {
-            lvgl_sys::_lv_style_set_opa(
-                self.raw.as_mut(),
-                (lvgl_sys::LV_STYLE_BG_OPA | (native_state << lvgl_sys::LV_STYLE_STATE_POS as u32))
-                    as u16,
-                value.into(),
-            );
-        }
-    }
-
-    pub fn set_border_width(&mut self, state: State, value: i16) {
-        let native_state: u32 = state.get_bits();
-        unsafe {
-            lvgl_sys::_lv_style_set_int(
-                self.raw.as_mut(),
-                (lvgl_sys::LV_STYLE_BORDER_WIDTH
-                    | (native_state << lvgl_sys::LV_STYLE_STATE_POS as u32)) as u16,
-                value,
-            );
-        }
-    }
-
-    pub fn set_border_side(&mut self, state: State, value: i16) {
-        let native_state: u32 = state.get_bits();
-        unsafe {
-            lvgl_sys::_lv_style_set_int(
-                self.raw.as_mut(),
-                (lvgl_sys::LV_STYLE_BORDER_SIDE
-                    | (native_state << lvgl_sys::LV_STYLE_STATE_POS as u32)) as u16,
-                value,
-            );
-        }
-    }
-
-    pub fn set_border_blend_mode(&mut self, state: State, value: i16) {
-        let native_state: u32 = state.get_bits();
-        unsafe {
-            lvgl_sys::_lv_style_set_int(
-                self.raw.as_mut(),
-                (lvgl_sys::LV_STYLE_BORDER_BLEND_MODE
-                    | (native_state << lvgl_sys::LV_STYLE_STATE_POS as u32)) as u16,
-                value,
-            );
-        }
-    }
-
-    pub fn set_border_post(&mut self, state: State, value: i16) {
-        let native_state: u32 = state.get_bits();
-        unsafe {
-            lvgl_sys::_lv_style_set_int(
-                self.raw.as_mut(),
-                (lvgl_sys::LV_STYLE_BORDER_POST
-                    | (native_state << lvgl_sys::LV_STYLE_STATE_POS as u32)) as u16,
-                value,
-            );
-        }
-    }
-
-    pub fn set_border_color(&mut self, state: State, value: Color) {
-        let native_state: u32 = state.get_bits();
-        unsafe {
-            lvgl_sys::_lv_style_set_color(
-                self.raw.as_mut(),
-                (lvgl_sys::LV_STYLE_BORDER_COLOR
-                    | (native_state << lvgl_sys::LV_STYLE_STATE_POS as u32)) as u16,
-                value.raw,
-            );
-        }
-    }
-
-    pub fn set_border_opa(&mut self, state: State, value: Opacity) {
-        let native_state: u32 = state.get_bits();
-        unsafe {
-            lvgl_sys::_lv_style_set_opa(
-                self.raw.as_mut(),
-                (lvgl_sys::LV_STYLE_BORDER_OPA
-                    | (native_state << lvgl_sys::LV_STYLE_STATE_POS as u32)) as u16,
-                value.into(),
-            );
-        }
-    }
-
-    pub fn set_outline_width(&mut self, state: State, value: i16) {
-        let native_state: u32 = state.get_bits();
-        unsafe {
-            lvgl_sys::_lv_style_set_int(
-                self.raw.as_mut(),
-                (lvgl_sys::LV_STYLE_OUTLINE_WIDTH
-                    | (native_state << lvgl_sys::LV_STYLE_STATE_POS as u32)) as u16,
-                value,
-            );
-        }
-    }
-
-    pub fn set_outline_pad(&mut self, state: State, value: i16) {
-        let native_state: u32 = state.get_bits();
-        unsafe {
-            lvgl_sys::_lv_style_set_int(
-                self.raw.as_mut(),
-                (lvgl_sys::LV_STYLE_OUTLINE_PAD
-                    | (native_state << lvgl_sys::LV_STYLE_STATE_POS as u32)) as u16,
-                value,
-            );
-        }
-    }
-
-    pub fn set_outline_blend_mode(&mut self, state: State, value: i16) {
-        let native_state: u32 = state.get_bits();
-        unsafe {
-            lvgl_sys::_lv_style_set_int(
-                self.raw.as_mut(),
-                (lvgl_sys::LV_STYLE_OUTLINE_BLEND_MODE
-                    | (native_state << lvgl_sys::LV_STYLE_STATE_POS as u32)) as u16,
-                value,
-            );
-        }
-    }
-
-    pub fn set_outline_color(&mut self, state: State, value: Color) {
-        let native_state: u32 = state.get_bits();
-        unsafe {
-            lvgl_sys::_lv_style_set_color(
-                self.raw.as_mut(),
-                (lvgl_sys::LV_STYLE_OUTLINE_COLOR
-                    | (native_state << lvgl_sys::LV_STYLE_STATE_POS as u32)) as u16,
-                value.raw,
-            );
-        }
-    }
-
-    pub fn set_outline_opa(&mut self, state: State, value: Opacity) {
-        let native_state: u32 = state.get_bits();
-        unsafe {
-            lvgl_sys::_lv_style_set_opa(
-                self.raw.as_mut(),
-                (lvgl_sys::LV_STYLE_OUTLINE_OPA
-                    | (native_state << lvgl_sys::LV_STYLE_STATE_POS as u32)) as u16,
-                value.into(),
-            );
-        }
-    }
-
-    pub fn set_shadow_width(&mut self, state: State, value: i16) {
-        let native_state: u32 = state.get_bits();
-        unsafe {
-            lvgl_sys::_lv_style_set_int(
-                self.raw.as_mut(),
-                (lvgl_sys::LV_STYLE_SHADOW_WIDTH
-                    | (native_state << lvgl_sys::LV_STYLE_STATE_POS as u32)) as u16,
-                value,
-            );
-        }
-    }
-
-    pub fn set_shadow_ofs_x(&mut self, state: State, value: i16) {
-        let native_state: u32 = state.get_bits();
-        unsafe {
-            lvgl_sys::_lv_style_set_int(
-                self.raw.as_mut(),
-                (lvgl_sys::LV_STYLE_SHADOW_OFS_X
-                    | (native_state << lvgl_sys::LV_STYLE_STATE_POS as u32)) as u16,
-                value,
-            );
-        }
-    }
-
-    pub fn set_shadow_ofs_y(&mut self, state: State, value: i16) {
-        let native_state: u32 = state.get_bits();
-        unsafe {
-            lvgl_sys::_lv_style_set_int(
-                self.raw.as_mut(),
-                (lvgl_sys::LV_STYLE_SHADOW_OFS_Y
-                    | (native_state << lvgl_sys::LV_STYLE_STATE_POS as u32)) as u16,
-                value,
-            );
-        }
-    }
-
-    pub fn set_shadow_spread(&mut self, state: State, value: i16) {
-        let native_state: u32 = state.get_bits();
-        unsafe {
-            lvgl_sys::_lv_style_set_int(
-                self.raw.as_mut(),
-                (lvgl_sys::LV_STYLE_SHADOW_SPREAD
-                    | (native_state << lvgl_sys::LV_STYLE_STATE_POS as u32)) as u16,
-                value,
-            );
-        }
-    }
-
-    pub fn set_shadow_blend_mode(&mut self, state: State, value: i16) {
-        let native_state: u32 = state.get_bits();
-        unsafe {
-            lvgl_sys::_lv_style_set_int(
-                self.raw.as_mut(),
-                (lvgl_sys::LV_STYLE_SHADOW_BLEND_MODE
-                    | (native_state << lvgl_sys::LV_STYLE_STATE_POS as u32)) as u16,
-                value,
-            );
-        }
-    }
-
-    pub fn set_shadow_color(&mut self, state: State, value: Color) {
-        let native_state: u32 = state.get_bits();
+    fn set_int(&mut self, prop: u32, state: State, value: i16) {
         unsafe {
-            lvgl_sys::_lv_style_set_color(
-                self.raw.as_mut(),
-                (lvgl_sys::LV_STYLE_SHADOW_COLOR
-                    | (native_state << lvgl_sys::LV_STYLE_STATE_POS as u32)) as u16,
-                value.raw,
-            );
+            lvgl_sys::_lv_style_set_int(self.raw.as_mut(), native_prop(prop, state), value);
         }
     }
 
-    pub fn set_shadow_opa(&mut self, state: State, value: Opacity) {
-        let native_state: u32 = state.get_bits();
+    fn set_color(&mut self, prop: u32, state: State, value: Color) {
         unsafe {
-            lvgl_sys::_lv_style_set_opa(
-                self.raw.as_mut(),
-                (lvgl_sys::LV_STYLE_SHADOW_OPA
-                    | (native_state << lvgl_sys::LV_STYLE_STATE_POS as u32)) as u16,
-                value.into(),
-            );
+            lvgl_sys::_lv_style_set_color(self.raw.as_mut(), native_prop(prop, state), value.raw);
         }
     }
 
-    pub fn set_pattern_repeat(&mut self, state: State, value: i16) {
-        let native_state: u32 = state.get_bits();
+    fn set_opa(&mut self, prop: u32, state: State, value: Opacity) {
         unsafe {
-            lvgl_sys::_lv_style_set_int(
-                self.raw.as_mut(),
-                (lvgl_sys::LV_STYLE_PATTERN_REPEAT
-                    | (native_state << lvgl_sys::LV_STYLE_STATE_POS as u32)) as u16,
-                value,
-            );
+            lvgl_sys::_lv_style_set_opa(self.raw.as_mut(), native_prop(prop, state), value.into());
         }
     }
 
-    pub fn set_pattern_blend_mode(&mut self, state: State, value: i16) {
-        let native_state: u32 = state.get_bits();
+    fn set_ptr(&mut self, prop: u32, state: State, value: *const cty::c_void) {
         unsafe {
-            lvgl_sys::_lv_style_set_int(
-                self.raw.as_mut(),
-                (lvgl_sys::LV_STYLE_PATTERN_BLEND_MODE
-                    | (native_state << lvgl_sys::LV_STYLE_STATE_POS as u32)) as u16,
-                value,
-            );
+            lvgl_sys::_lv_style_set_ptr(self.raw.as_mut(), native_prop(prop, state), value);
         }
     }
 
-    pub fn set_pattern_recolor(&mut self, state: State, value: Color) {
-        let native_state: u32 = state.get_bits();
+    /// The style stores its own copy of the string.
+    fn set_str(&mut self, prop: u32, state: State, value: &CStr) -> LvResult<()> {
+        let prop = native_prop(prop, state);
+        let owned = OwnedValue::from_cstr(prop, value)?;
         unsafe {
-            lvgl_sys::_lv_style_set_color(
-                self.raw.as_mut(),
-                (lvgl_sys::LV_STYLE_PATTERN_RECOLOR
-                    | (native_state << lvgl_sys::LV_STYLE_STATE_POS as u32)) as u16,
-                value.raw,
-            );
-        }
-    }
-
-    pub fn set_pattern_opa(&mut self, state: State, value: Opacity) {
-        let native_state: u32 = state.get_bits();
-        unsafe {
-            lvgl_sys::_lv_style_set_opa(
-                self.raw.as_mut(),
-                (lvgl_sys::LV_STYLE_PATTERN_OPA
-                    | (native_state << lvgl_sys::LV_STYLE_STATE_POS as u32)) as u16,
-                value.into(),
-            );
-        }
-    }
-
-    pub fn set_pattern_recolor_opa(&mut self, state: State, value: Opacity) {
-        let native_state: u32 = state.get_bits();
-        unsafe {
-            lvgl_sys::_lv_style_set_opa(
-                self.raw.as_mut(),
-                (lvgl_sys::LV_STYLE_PATTERN_RECOLOR_OPA
-                    | (native_state << lvgl_sys::LV_STYLE_STATE_POS as u32)) as u16,
-                value.into(),
-            );
-        }
-    }
-
-    pub fn set_value_letter_space(&mut self, state: State, value: i16) {
-        let native_state: u32 = state.get_bits();
-        unsafe {
-            lvgl_sys::_lv_style_set_int(
-                self.raw.as_mut(),
-                (lvgl_sys::LV_STYLE_VALUE_LETTER_SPACE
-                    | (native_state << lvgl_sys::LV_STYLE_STATE_POS as u32)) as u16,
-                value,
-            );
-        }
-    }
-
-    pub fn set_value_line_space(&mut self, state: State, value: i16) {
-        let native_state: u32 = state.get_bits();
-        unsafe {
-            lvgl_sys::_lv_style_set_int(
-                self.raw.as_mut(),
-                (lvgl_sys::LV_STYLE_VALUE_LINE_SPACE
-                    | (native_state << lvgl_sys::LV_STYLE_STATE_POS as u32)) as u16,
-                value,
-            );
-        }
-    }
-
-    pub fn set_value_blend_mode(&mut self, state: State, value: i16) {
-        let native_state: u32 = state.get_bits();
-        unsafe {
-            lvgl_sys::_lv_style_set_int(
-                self.raw.as_mut(),
-                (lvgl_sys::LV_STYLE_VALUE_BLEND_MODE
-                    | (native_state << lvgl_sys::LV_STYLE_STATE_POS as u32)) as u16,
-                value,
-            );
+            lvgl_sys::_lv_style_set_ptr(self.raw.as_mut(), prop, owned.ptr.as_ptr());
         }
+        self.retain(owned);
+        Ok(())
     }
 
-    pub fn set_value_ofs_x(&mut self, state: State, value: i16) {
-        let native_state: u32 = state.get_bits();
-        unsafe {
-            lvgl_sys::_lv_style_set_int(
-                self.raw.as_mut(),
-                (lvgl_sys::LV_STYLE_VALUE_OFS_X
-                    | (native_state << lvgl_sys::LV_STYLE_STATE_POS as u32)) as u16,
-                value,
-            );
-        }
+    fn set_font(&mut self, prop: u32, state: State, value: Font) {
+        self.set_ptr(prop, state, value.raw() as *const cty::c_void);
     }
 
-    pub fn set_value_ofs_y(&mut self, state: State, value: i16) {
-        let native_state: u32 = state.get_bits();
-        unsafe {
-            lvgl_sys::_lv_style_set_int(
-                self.raw.as_mut(),
-                (lvgl_sys::LV_STYLE_VALUE_OFS_Y
-                    | (native_state << lvgl_sys::LV_STYLE_STATE_POS as u32)) as u16,
-                value,
-            );
-        }
+    fn set_image(&mut self, prop: u32, state: State, value: ImageSource) {
+        self.set_ptr(prop, state, value.as_ptr());
     }
 
-    pub fn set_value_align(&mut self, state: State, value: i16) {
-        let native_state: u32 = state.get_bits();
-        unsafe {
-            lvgl_sys::_lv_style_set_int(
-                self.raw.as_mut(),
-                (lvgl_sys::LV_STYLE_VALUE_ALIGN
-                    | (native_state << lvgl_sys::LV_STYLE_STATE_POS as u32)) as u16,
-                value,
-            );
-        }
+    fn set_anim_path(&mut self, prop: u32, state: State, value: &'static AnimPath) {
+        self.set_ptr(prop, state, value.raw() as *const cty::c_void);
     }
 
-    pub fn set_value_color(&mut self, state: State, value: Color) {
-        let native_state: u32 = state.get_bits();
-        unsafe {
-            lvgl_sys::_lv_style_set_color(
-                self.raw.as_mut(),
-                (lvgl_sys::LV_STYLE_VALUE_COLOR
-                    | (native_state << lvgl_sys::LV_STYLE_STATE_POS as u32)) as u16,
-                value.raw,
-            );
+    /// Animate the change of the `transition` properties when the object enters `state`.
+    pub fn set_transition(&mut self, state: State, transition: Transition) {
+        self.set_transition_time(state, duration_to_millis(transition.duration));
+        self.set_transition_delay(state, duration_to_millis(transition.delay));
+        let mut props = transition.iter_props();
+        for slot in TRANSITION_PROPS.iter() {
+            match props.next() {
+                Some(prop) => self.set_int(*slot, state, prop.bits() as i16),
+                // Clear the properties of a previous, longer transition
+                None => unsafe {
+                    lvgl_sys::lv_style_remove_prop(self.raw.as_mut(), native_prop(*slot, state));
+                },
+            }
         }
-    }
-
-    pub fn set_value_opa(&mut self, state: State, value: Opacity) {
-        let native_state: u32 = state.get_bits();
-        unsafe {
-            lvgl_sys::_lv_style_set_opa(
-                self.raw.as_mut(),
-                (lvgl_sys::LV_STYLE_VALUE_OPA
-                    | (native_state << lvgl_sys::LV_STYLE_STATE_POS as u32)) as u16,
-                value.into(),
-            );
+        match transition.path {
+            Some(TransitionPath::Static(path)) => self.set_transition_path(state, path),
+            Some(TransitionPath::Owned(mut path)) => {
+                path.prop = native_prop(lvgl_sys::LV_STYLE_TRANSITION_PATH, state);
+                unsafe {
+                    lvgl_sys::_lv_style_set_ptr(self.raw.as_mut(), path.prop, path.ptr.as_ptr());
+                }
+                self.retain(path);
+            }
+            None => {}
         }
     }
 
-    pub fn set_text_letter_space(&mut self, state: State, value: i16) {
-        let native_state: u32 = state.get_bits();
-        unsafe {
-            lvgl_sys::_lv_style_set_int(
-                self.raw.as_mut(),
-                (lvgl_sys::LV_STYLE_TEXT_LETTER_SPACE
-                    | (native_state << lvgl_sys::LV_STYLE_STATE_POS as u32)) as u16,
-                value,
-            );
-        }
-    }
-
-    pub fn set_text_line_space(&mut self, state: State, value: i16) {
-        let native_state: u32 = state.get_bits();
-        unsafe {
-            lvgl_sys::_lv_style_set_int(
-                self.raw.as_mut(),
-                (lvgl_sys::LV_STYLE_TEXT_LINE_SPACE
-                    | (native_state << lvgl_sys::LV_STYLE_STATE_POS as u32)) as u16,
-                value,
-            );
+    fn get_int(&self, prop: u32, state: State) -> Option<i16> {
+        let mut value: lvgl_sys::lv_style_int_t = 0;
+        let weight = unsafe {
+            lvgl_sys::_lv_style_get_int(
+                &*self.raw,
+                native_prop(prop, state),
+                &mut value as *mut _ as *mut cty::c_void,
+            )
+        };
+        if weight >= 0 {
+            Some(value)
+        } else {
+            None
         }
     }
 
-    pub fn set_text_decor(&mut self, state: State, value: i16) {
-        let native_state: u32 = state.get_bits();
-        unsafe {
-            lvgl_sys::_lv_style_set_int(
-                self.raw.as_mut(),
-                (lvgl_sys::LV_STYLE_TEXT_DECOR
-                    | (native_state << lvgl_sys::LV_STYLE_STATE_POS as u32)) as u16,
-                value,
-            );
+    fn get_color(&self, prop: u32, state: State) -> Option<Color> {
+        let mut value = mem::MaybeUninit::<lvgl_sys::lv_color_t>::uninit();
+        let weight = unsafe {
+            lvgl_sys::_lv_style_get_color(
+                &*self.raw,
+                native_prop(prop, state),
+                value.as_mut_ptr() as *mut cty::c_void,
+            )
+        };
+        if weight >= 0 {
+            Some(Color::from_raw(unsafe { value.assume_init() }))
+        } else {
+            None
         }
     }
 
-    pub fn set_text_blend_mode(&mut self, state: State, value: i16) {
-        let native_state: u32 = state.get_bits();
-        unsafe {
-            lvgl_sys::_lv_style_set_int(
-                self.raw.as_mut(),
-                (lvgl_sys::LV_STYLE_TEXT_BLEND_MODE
-                    | (native_state << lvgl_sys::LV_STYLE_STATE_POS as u32)) as u16,
-                value,
-            );
+    fn get_opa(&self, prop: u32, state: State) -> Option<Opacity> {
+        let mut value: lvgl_sys::lv_opa_t = 0;
+        let weight = unsafe {
+            lvgl_sys::_lv_style_get_opa(
+                &*self.raw,
+                native_prop(prop, state),
+                &mut value as *mut _ as *mut cty::c_void,
+            )
+        };
+        if weight >= 0 {
+            Some(Opacity::from_bits_truncate(value as u32))
+        } else {
+            None
         }
     }
 
-    pub fn set_text_color(&mut self, state: State, value: Color) {
-        let native_state: u32 = state.get_bits();
-        unsafe {
-            lvgl_sys::_lv_style_set_color(
-                self.raw.as_mut(),
-                (lvgl_sys::LV_STYLE_TEXT_COLOR
-                    | (native_state << lvgl_sys::LV_STYLE_STATE_POS as u32)) as u16,
-                value.raw,
-            );
+    fn get_ptr(&self, prop: u32, state: State) -> Option<NonNull<cty::c_void>> {
+        let mut value: *const cty::c_void = ptr::null();
+        let weight = unsafe {
+            lvgl_sys::_lv_style_get_ptr(&*self.raw, native_prop(prop, state), &mut value)
+        };
+        if weight >= 0 {
+            NonNull::new(value as *mut cty::c_void)
+        } else {
+            None
         }
     }
 
-    pub fn set_text_sel_color(&mut self, state: State, value: Color) {
-        let native_state: u32 = state.get_bits();
-        unsafe {
-            lvgl_sys::_lv_style_set_color(
-                self.raw.as_mut(),
-                (lvgl_sys::LV_STYLE_TEXT_SEL_COLOR
-                    | (native_state << lvgl_sys::LV_STYLE_STATE_POS as u32)) as u16,
-                value.raw,
-            );
-        }
+    fn get_str(&self, prop: u32, state: State) -> Option<&CStr> {
+        self.get_ptr(prop, state)
+            .map(|p| unsafe { CStr::from_ptr(p.as_ptr() as *const cty::c_char) })
     }
 
-    pub fn set_text_opa(&mut self, state: State, value: Opacity) {
-        let native_state: u32 = state.get_bits();
-        unsafe {
-            lvgl_sys::_lv_style_set_opa(
-                self.raw.as_mut(),
-                (lvgl_sys::LV_STYLE_TEXT_OPA
-                    | (native_state << lvgl_sys::LV_STYLE_STATE_POS as u32)) as u16,
-                value.into(),
-            );
-        }
+    fn get_font(&self, prop: u32, state: State) -> Option<Font> {
+        // Fonts can only be set from a `'static` reference
+        self.get_ptr(prop, state)
+            .map(|p| Font::from_raw(unsafe { &*(p.as_ptr() as *const lvgl_sys::lv_font_t) }))
     }
 
-    pub fn set_line_width(&mut self, state: State, value: i16) {
-        let native_state: u32 = state.get_bits();
-        unsafe {
-            lvgl_sys::_lv_style_set_int(
-                self.raw.as_mut(),
-                (lvgl_sys::LV_STYLE_LINE_WIDTH
-                    | (native_state << lvgl_sys::LV_STYLE_STATE_POS as u32)) as u16,
-                value,
-            );
+    /// Keep `value` alive for as long as the style, replacing the value previously owned for
+    /// the same property and state. Must be called after the style points to the new value.
+    fn retain(&mut self, mut value: Box<OwnedValue>) {
+        let mut next = self.owned.take();
+        while let Some(mut current) = next {
+            next = current.next.take();
+            if current.prop != value.prop {
+                current.next = value.next.take();
+                value.next = Some(current);
+            }
         }
+        self.owned = Some(value);
     }
 
-    pub fn set_line_blend_mode(&mut self, state: State, value: i16) {
-        let native_state: u32 = state.get_bits();
-        unsafe {
-            lvgl_sys::_lv_style_set_int(
-                self.raw.as_mut(),
-                (lvgl_sys::LV_STYLE_LINE_BLEND_MODE
-                    | (native_state << lvgl_sys::LV_STYLE_STATE_POS as u32)) as u16,
-                value,
-            );
-        }
+    /// Hand over the style to LVGL. The style and the values it owns are never released.
+    pub(crate) fn into_raw(self) -> *mut lvgl_sys::lv_style_t {
+        let mut style = mem::ManuallyDrop::new(self);
+        style.raw.as_mut() as *mut _
     }
+}
 
-    pub fn set_line_dash_width(&mut self, state: State, value: i16) {
-        let native_state: u32 = state.get_bits();
-        unsafe {
-            lvgl_sys::_lv_style_set_int(
-                self.raw.as_mut(),
-                (lvgl_sys::LV_STYLE_LINE_DASH_WIDTH
-                    | (native_state << lvgl_sys::LV_STYLE_STATE_POS as u32)) as u16,
-                value,
-            );
-        }
+impl Default for Style {
+    fn default() -> Self {
+        let raw = unsafe {
+            let mut style = mem::MaybeUninit::<lvgl_sys::lv_style_t>::uninit();
+            lvgl_sys::lv_style_init(style.as_mut_ptr());
+            Box::new(style.assume_init())
+        };
+        Self { raw, owned: None }
     }
+}
 
-    pub fn set_line_dash_gap(&mut self, state: State, value: i16) {
-        let native_state: u32 = state.get_bits();
+impl Drop for Style {
+    fn drop(&mut self) {
         unsafe {
-            lvgl_sys::_lv_style_set_int(
-                self.raw.as_mut(),
-                (lvgl_sys::LV_STYLE_LINE_DASH_GAP
-                    | (native_state << lvgl_sys::LV_STYLE_STATE_POS as u32)) as u16,
-                value,
-            );
+            // Releases the memory LVGL allocated for the properties
+            lvgl_sys::lv_style_reset(self.raw.as_mut());
         }
     }
+}
 
-    pub fn set_line_rounded(&mut self, state: State, value: i16) {
-        let native_state: u32 = state.get_bits();
-        unsafe {
-            lvgl_sys::_lv_style_set_int(
-                self.raw.as_mut(),
-                (lvgl_sys::LV_STYLE_LINE_ROUNDED
-                    | (native_state << lvgl_sys::LV_STYLE_STATE_POS as u32)) as u16,
-                value,
-            );
-        }
-    }
+const TRANSITION_PROPS: [u32; lvgl_sys::LV_STYLE_TRANS_NUM_MAX as usize] = [
+    lvgl_sys::LV_STYLE_TRANSITION_PROP_1,
+    lvgl_sys::LV_STYLE_TRANSITION_PROP_2,
+    lvgl_sys::LV_STYLE_TRANSITION_PROP_3,
+    lvgl_sys::LV_STYLE_TRANSITION_PROP_4,
+    lvgl_sys::LV_STYLE_TRANSITION_PROP_5,
+    lvgl_sys::LV_STYLE_TRANSITION_PROP_6,
+];
+
+/// Describes how style properties are animated when the state of an object changes, e.g. to
+/// smoothly change the background color of a button when it is pressed.
+///
+/// ```ignore
+/// let transition = Transition::new(&[StyleProp::BG_COLOR, StyleProp::BORDER_WIDTH])?
+///     .duration(Duration::from_millis(300))
+///     .path(&AnimPath::EASE_OUT);
+/// style.set_transition(State::PRESSED, transition);
+/// ```
+pub struct Transition {
+    props: [Option<StyleProp>; lvgl_sys::LV_STYLE_TRANS_NUM_MAX as usize],
+    duration: Duration,
+    delay: Duration,
+    path: Option<TransitionPath>,
+}
 
-    pub fn set_line_color(&mut self, state: State, value: Color) {
-        let native_state: u32 = state.get_bits();
-        unsafe {
-            lvgl_sys::_lv_style_set_color(
-                self.raw.as_mut(),
-                (lvgl_sys::LV_STYLE_LINE_COLOR
-                    | (native_state << lvgl_sys::LV_STYLE_STATE_POS as u32)) as u16,
-                value.raw,
-            );
-        }
-    }
+enum TransitionPath {
+    Static(&'static AnimPath),
+    Owned(Box<OwnedValue>),
+}
 
-    pub fn set_line_opa(&mut self, state: State, value: Opacity) {
-        let native_state: u32 = state.get_bits();
-        unsafe {
-            lvgl_sys::_lv_style_set_opa(
-                self.raw.as_mut(),
-                (lvgl_sys::LV_STYLE_LINE_OPA
-                    | (native_state << lvgl_sys::LV_STYLE_STATE_POS as u32)) as u16,
-                value.into(),
-            );
+impl Transition {
+    /// Animate the given properties. LVGL supports at most `LV_STYLE_TRANS_NUM_MAX` (six)
+    /// properties per transition, fails with `LvError::CapacityExceeded` for more.
+    pub fn new(props: &[StyleProp]) -> LvResult<Self> {
+        if props.len() > lvgl_sys::LV_STYLE_TRANS_NUM_MAX as usize {
+            return Err(LvError::CapacityExceeded);
+        }
+        let mut transition = Self {
+            props: [None; lvgl_sys::LV_STYLE_TRANS_NUM_MAX as usize],
+            duration: Duration::from_millis(0),
+            delay: Duration::from_millis(0),
+            path: None,
+        };
+        for (slot, prop) in transition.props.iter_mut().zip(props) {
+            *slot = Some(*prop);
         }
+        Ok(transition)
     }
 
-    pub fn set_image_blend_mode(&mut self, state: State, value: i16) {
-        let native_state: u32 = state.get_bits();
-        unsafe {
-            lvgl_sys::_lv_style_set_int(
-                self.raw.as_mut(),
-                (lvgl_sys::LV_STYLE_IMAGE_BLEND_MODE
-                    | (native_state << lvgl_sys::LV_STYLE_STATE_POS as u32)) as u16,
-                value,
-            );
-        }
+    /// How long the animation takes.
+    pub fn duration(mut self, duration: Duration) -> Self {
+        self.duration = duration;
+        self
     }
 
-    pub fn set_image_recolor(&mut self, state: State, value: Color) {
-        let native_state: u32 = state.get_bits();
-        unsafe {
-            lvgl_sys::_lv_style_set_color(
-                self.raw.as_mut(),
-                (lvgl_sys::LV_STYLE_IMAGE_RECOLOR
-                    | (native_state << lvgl_sys::LV_STYLE_STATE_POS as u32)) as u16,
-                value.raw,
-            );
-        }
+    /// How long to wait before starting the animation.
+    pub fn delay(mut self, delay: Duration) -> Self {
+        self.delay = delay;
+        self
     }
 
-    pub fn set_image_opa(&mut self, state: State, value: Opacity) {
-        let native_state: u32 = state.get_bits();
-        unsafe {
-            lvgl_sys::_lv_style_set_opa(
-                self.raw.as_mut(),
-                (lvgl_sys::LV_STYLE_IMAGE_OPA
-                    | (native_state << lvgl_sys::LV_STYLE_STATE_POS as u32)) as u16,
-                value.into(),
-            );
-        }
+    /// Use one of the LVGL easing functions, e.g. `AnimPath::EASE_IN_OUT`.
+    pub fn path(mut self, path: &'static AnimPath) -> Self {
+        self.path = Some(TransitionPath::Static(path));
+        self
     }
 
-    pub fn set_image_recolor_opa(&mut self, state: State, value: Opacity) {
-        let native_state: u32 = state.get_bits();
-        unsafe {
-            lvgl_sys::_lv_style_set_opa(
-                self.raw.as_mut(),
-                (lvgl_sys::LV_STYLE_IMAGE_RECOLOR_OPA
-                    | (native_state << lvgl_sys::LV_STYLE_STATE_POS as u32)) as u16,
-                value.into(),
-            );
-        }
+    /// Use a custom easing function.
+    ///
+    /// The closure maps the elapsed time to the progress of the animation, both in the range
+    /// `0..=LV_ANIM_RESOLUTION` (`1024`). The returned progress can be out of this range, e.g.
+    /// to overshoot the end value.
+    pub fn path_fn<F>(mut self, f: F) -> Self
+    where
+        F: Fn(i32) -> i32 + 'static,
+    {
+        let mut path = Box::new(ClosurePath {
+            raw: lvgl_sys::lv_anim_path_t {
+                cb: Some(anim_path_callback::<F>),
+                user_data: ptr::null_mut(),
+            },
+            f,
+        });
+        path.raw.user_data = &mut path.f as *mut F as *mut cty::c_void;
+        self.path = Some(TransitionPath::Owned(OwnedValue::new(path)));
+        self
     }
 
-    pub fn set_transition_time(&mut self, state: State, value: i16) {
-        let native_state: u32 = state.get_bits();
-        unsafe {
-            lvgl_sys::_lv_style_set_int(
-                self.raw.as_mut(),
-                (lvgl_sys::LV_STYLE_TRANSITION_TIME
-                    | (native_state << lvgl_sys::LV_STYLE_STATE_POS as u32)) as u16,
-                value,
-            );
-        }
+    fn iter_props(&self) -> impl Iterator<Item = StyleProp> + '_ {
+        self.props.iter().filter_map(|p| *p)
     }
+}
 
-    pub fn set_transition_delay(&mut self, state: State, value: i16) {
-        let native_state: u32 = state.get_bits();
-        unsafe {
-            lvgl_sys::_lv_style_set_int(
-                self.raw.as_mut(),
-                (lvgl_sys::LV_STYLE_TRANSITION_DELAY
-                    | (native_state << lvgl_sys::LV_STYLE_STATE_POS as u32)) as u16,
-                value,
-            );
-        }
-    }
+fn duration_to_millis(duration: Duration) -> i16 {
+    core::cmp::min(duration.as_millis(), i16::MAX as u128) as i16
+}
 
-    pub fn set_transition_prop_1(&mut self, state: State, value: i16) {
-        let native_state: u32 = state.get_bits();
-        unsafe {
-            lvgl_sys::_lv_style_set_int(
-                self.raw.as_mut(),
-                (lvgl_sys::LV_STYLE_TRANSITION_PROP_1
-                    | (native_state << lvgl_sys::LV_STYLE_STATE_POS as u32)) as u16,
-                value,
-            );
-        }
-    }
+// The style points to the whole struct, which LVGL reads as an `lv_anim_path_t`, so `raw` must
+// be the first field. Its `user_data` points to `f`.
+#[repr(C)]
+struct ClosurePath<F> {
+    raw: lvgl_sys::lv_anim_path_t,
+    f: F,
+}
 
-    pub fn set_transition_prop_2(&mut self, state: State, value: i16) {
-        let native_state: u32 = state.get_bits();
-        unsafe {
-            lvgl_sys::_lv_style_set_int(
-                self.raw.as_mut(),
-                (lvgl_sys::LV_STYLE_TRANSITION_PROP_2
-                    | (native_state << lvgl_sys::LV_STYLE_STATE_POS as u32)) as u16,
-                value,
-            );
-        }
-    }
+unsafe extern "C" fn anim_path_callback<F>(
+    path: *const lvgl_sys::lv_anim_path_t,
+    anim: *const lvgl_sys::lv_anim_t,
+) -> lvgl_sys::lv_anim_value_t
+where
+    F: Fn(i32) -> i32,
+{
+    let f = &*((*path).user_data as *const F);
+    let anim = &*anim;
+    let resolution = lvgl_sys::LV_ANIM_RESOLUTION as i64;
+    let progress = if anim.time as i64 <= 0 {
+        resolution
+    } else {
+        core::cmp::min(
+            anim.act_time as i64 * resolution / anim.time as i64,
+            resolution,
+        )
+    };
+    let step = f(progress as i32) as i64;
+    let delta = anim.end as i64 - anim.start as i64;
+    ((step * delta) / resolution + anim.start as i64) as lvgl_sys::lv_anim_value_t
+}
 
-    pub fn set_transition_prop_3(&mut self, state: State, value: i16) {
-        let native_state: u32 = state.get_bits();
-        unsafe {
-            lvgl_sys::_lv_style_set_int(
-                self.raw.as_mut(),
-                (lvgl_sys::LV_STYLE_TRANSITION_PROP_3
-                    | (native_state << lvgl_sys::LV_STYLE_STATE_POS as u32)) as u16,
-                value,
-            );
-        }
-    }
+/// A value allocated in LVGL memory that is referenced by a style property.
+struct OwnedValue {
+    prop: u16,
+    ptr: NonNull<cty::c_void>,
+    free: unsafe fn(NonNull<cty::c_void>),
+    next: Option<Box<OwnedValue>>,
+}
 
-    pub fn set_transition_prop_4(&mut self, state: State, value: i16) {
-        let native_state: u32 = state.get_bits();
-        unsafe {
-            lvgl_sys::_lv_style_set_int(
-                self.raw.as_mut(),
-                (lvgl_sys::LV_STYLE_TRANSITION_PROP_4
-                    | (native_state << lvgl_sys::LV_STYLE_STATE_POS as u32)) as u16,
-                value,
-            );
-        }
+impl OwnedValue {
+    fn new<T>(value: Box<T>) -> Box<Self> {
+        Box::new(Self {
+            prop: 0,
+            ptr: unsafe { NonNull::new_unchecked(Box::into_raw(value) as *mut cty::c_void) },
+            free: drop_box::<T>,
+            next: None,
+        })
     }
 
-    pub fn set_transition_prop_5(&mut self, state: State, value: i16) {
-        let native_state: u32 = state.get_bits();
+    fn from_cstr(prop: u16, value: &CStr) -> LvResult<Box<Self>> {
+        let bytes = value.to_bytes_with_nul();
         unsafe {
-            lvgl_sys::_lv_style_set_int(
-                self.raw.as_mut(),
-                (lvgl_sys::LV_STYLE_TRANSITION_PROP_5
-                    | (native_state << lvgl_sys::LV_STYLE_STATE_POS as u32)) as u16,
-                value,
-            );
+            let ptr = lvgl_sys::lv_mem_alloc(bytes.len() as lvgl_sys::size_t);
+            let ptr = NonNull::new(ptr).ok_or(LvError::LvOOMemory)?;
+            ptr::copy_nonoverlapping(bytes.as_ptr(), ptr.as_ptr() as *mut u8, bytes.len());
+            Ok(Box::new(Self {
+                prop,
+                ptr,
+                free: free_mem,
+                next: None,
+            }))
         }
     }
+}
 
-    pub fn set_transition_prop_6(&mut self, state: State, value: i16) {
-        let native_state: u32 = state.get_bits();
+impl Drop for OwnedValue {
+    fn drop(&mut self) {
         unsafe {
-            lvgl_sys::_lv_style_set_int(
-                self.raw.as_mut(),
-                (lvgl_sys::LV_STYLE_TRANSITION_PROP_6
-                    | (native_state << lvgl_sys::LV_STYLE_STATE_POS as u32)) as u16,
-                value,
-            );
+            (self.free)(self.ptr);
         }
     }
+}
 
-    pub fn set_scale_width(&mut self, state: State, value: i16) {
-        let native_state: u32 = state.get_bits();
-        unsafe {
-            lvgl_sys::_lv_style_set_int(
-                self.raw.as_mut(),
-                (lvgl_sys::LV_STYLE_SCALE_WIDTH
-                    | (native_state << lvgl_sys::LV_STYLE_STATE_POS as u32)) as u16,
-                value,
-            );
-        }
-    }
+unsafe fn free_mem(ptr: NonNull<cty::c_void>) {
+    lvgl_sys::lv_mem_free(ptr.as_ptr());
+}
 
-    pub fn set_scale_border_width(&mut self, state: State, value: i16) {
-        let native_state: u32 = state.get_bits();
-        unsafe {
-            lvgl_sys::_lv_style_set_int(
-                self.raw.as_mut(),
-                (lvgl_sys::LV_STYLE_SCALE_BORDER_WIDTH
-                    | (native_state << lvgl_sys::LV_STYLE_STATE_POS as u32)) as u16,
-                value,
-            );
-        }
-    }
+unsafe fn drop_box<T>(ptr: NonNull<cty::c_void>) {
+    drop(Box::from_raw(ptr.as_ptr() as *mut T));
+}
 
-    pub fn set_scale_end_border_width(&mut self, state: State, value: i16) {
-        let native_state: u32 = state.get_bits();
-        unsafe {
-            lvgl_sys::_lv_style_set_int(
-                self.raw.as_mut(),
-                (lvgl_sys::LV_STYLE_SCALE_END_BORDER_WIDTH
-                    | (native_state << lvgl_sys::LV_STYLE_STATE_POS as u32)) as u16,
-                value,
-            );
-        }
+bitflags! {
+    pub struct Opacity: u32 {
+        const OPA_TRANSP = lvgl_sys::LV_OPA_TRANSP;
+        const OPA_0 = lvgl_sys::LV_OPA_0;
+        const OPA_10 = lvgl_sys::LV_OPA_10;
+        const OPA_20 = lvgl_sys::LV_OPA_20;
+        const OPA_30 = lvgl_sys::LV_OPA_30;
+        const OPA_40 = lvgl_sys::LV_OPA_40;
+        const OPA_50 = lvgl_sys::LV_OPA_50;
+        const OPA_60 = lvgl_sys::LV_OPA_60;
+        const OPA_70 = lvgl_sys::LV_OPA_70;
+        const OPA_80 = lvgl_sys::LV_OPA_80;
+        const OPA_90 = lvgl_sys::LV_OPA_90;
+        const OPA_100 = lvgl_sys::LV_OPA_100;
+        const OPA_COVER = lvgl_sys::LV_OPA_COVER;
     }
+}
 
-    pub fn set_scale_end_line_width(&mut self, state: State, value: i16) {
-        let native_state: u32 = state.get_bits();
-        unsafe {
-            lvgl_sys::_lv_style_set_int(
-                self.raw.as_mut(),
-                (lvgl_sys::LV_STYLE_SCALE_END_LINE_WIDTH
-                    | (native_state << lvgl_sys::LV_STYLE_STATE_POS as u32)) as u16,
-                value,
-            );
-        }
+impl Into<u8> for Opacity {
+    fn into(self) -> u8 {
+        self.bits as u8
     }
+}
 
-    pub fn set_scale_grad_color(&mut self, state: State, value: Color) {
-        let native_state: u32 = state.get_bits();
-        unsafe {
-            lvgl_sys::_lv_style_set_color(
-                self.raw.as_mut(),
-                (lvgl_sys::LV_STYLE_SCALE_GRAD_COLOR
-                    | (native_state << lvgl_sys::LV_STYLE_STATE_POS as u32)) as u16,
-                value.raw,
-            );
-        }
-    }
+fn native_prop(prop: u32, state: State) -> u16 {
+    (prop | (state.get_bits() << lvgl_sys::LV_STYLE_STATE_POS as u32)) as u16
+}
 
-    pub fn set_scale_end_color(&mut self, state: State, value: Color) {
-        let native_state: u32 = state.get_bits();
-        unsafe {
-            lvgl_sys::_lv_style_set_color(
-                self.raw.as_mut(),
-                (lvgl_sys::LV_STYLE_SCALE_END_COLOR
-                    | (native_state << lvgl_sys::LV_STYLE_STATE_POS as u32)) as u16,
-                value.raw,
-            );
-        }
+// Style properties and their accessors are generated by lvgl-codegen.
+include!(concat!(env!("OUT_DIR"), "/generated_style.rs"));
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn style_owns_a_copy_of_value_str() {
+        crate::lvgl_init();
+
+        let mut style = Style::default();
+        let mut text = *b"Hello\0";
+        style
+            .set_value_str(State::DEFAULT, CStr::from_bytes_with_nul(&text).unwrap())
+            .unwrap();
+        text[0] = b'J';
+
+        let value = style.get_value_str(State::DEFAULT).unwrap();
+        assert_eq!(value.to_bytes(), b"Hello");
+        assert_eq!(&text, b"Jello\0");
+    }
+
+    #[test]
+    fn transition_path_calls_the_closure() {
+        crate::lvgl_init();
+
+        let mut style = Style::default();
+        let transition = Transition::new(&[StyleProp::BG_COLOR])
+            .unwrap()
+            .path_fn(|progress| progress / 2);
+        style.set_transition(State::DEFAULT, transition);
+
+        let path = style
+            .get_ptr(lvgl_sys::LV_STYLE_TRANSITION_PATH, State::DEFAULT)
+            .unwrap();
+        let path = unsafe { &*(path.as_ptr() as *const lvgl_sys::lv_anim_path_t) };
+        let mut anim = unsafe { mem::MaybeUninit::<lvgl_sys::lv_anim_t>::zeroed().assume_init() };
+        anim.start = 100;
+        anim.end = 300;
+        anim.time = 1000;
+        anim.act_time = 500;
+        // Half way through the animation, the closure returns a quarter of the resolution
+        let value = unsafe { (path.cb.unwrap())(path, &anim) };
+        assert_eq!(value, 150);
     }
 }
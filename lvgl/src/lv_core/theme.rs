@@ -0,0 +1,329 @@
+use crate::lv_core::style::Style;
+use crate::Box;
+use crate::{Color, Font, LvError, LvResult, NativeObject, Obj, Widget};
+use core::marker::PhantomData;
+use core::mem::MaybeUninit;
+use core::ptr::NonNull;
+
+/// A theme gives a default appearance to every new object, according to its type.
+///
+/// LVGL keeps using a theme for as long as the program runs, so themes are never released.
+pub struct Theme {
+    raw: NonNull<lvgl_sys::lv_theme_t>,
+}
+
+impl Theme {
+    /// The theme currently used by LVGL.
+    pub fn active() -> LvResult<Self> {
+        crate::lvgl_init();
+        let raw = unsafe { lvgl_sys::lv_theme_get_act() };
+        Ok(Self {
+            raw: NonNull::new(raw).ok_or(LvError::InvalidReference)?,
+        })
+    }
+
+    /// The Material Design inspired theme, the default theme of LVGL.
+    pub fn material(config: &ThemeConfig) -> LvResult<Self> {
+        Self::init(lvgl_sys::lv_theme_material_init, config)
+    }
+
+    /// A simple black and white theme for monochrome displays.
+    pub fn mono(config: &ThemeConfig) -> LvResult<Self> {
+        Self::init(lvgl_sys::lv_theme_mono_init, config)
+    }
+
+    /// A theme that does not add any styles.
+    pub fn empty(config: &ThemeConfig) -> LvResult<Self> {
+        Self::init(lvgl_sys::lv_theme_empty_init, config)
+    }
+
+    /// The simple template theme shipped with LVGL.
+    pub fn template(config: &ThemeConfig) -> LvResult<Self> {
+        Self::init(lvgl_sys::lv_theme_template_init, config)
+    }
+
+    /// Create a theme that applies the styles of `custom` on top of the `base` theme.
+    pub fn custom<T>(base: Theme, custom: T) -> Self
+    where
+        T: CustomTheme,
+    {
+        let mut theme = Box::new(CustomThemeData {
+            raw: unsafe { MaybeUninit::<lvgl_sys::lv_theme_t>::zeroed().assume_init() },
+            custom,
+        });
+        unsafe {
+            lvgl_sys::lv_theme_copy(&mut theme.raw, base.raw.as_ptr());
+            lvgl_sys::lv_theme_set_base(&mut theme.raw, base.raw.as_ptr());
+            lvgl_sys::lv_theme_set_apply_cb(&mut theme.raw, Some(apply_callback::<T>));
+        }
+        theme.raw.user_data = &mut theme.custom as *mut T as *mut cty::c_void;
+        let theme = Box::into_raw(theme);
+        Self {
+            raw: unsafe { NonNull::new_unchecked(&mut (*theme).raw) },
+        }
+    }
+
+    fn init(
+        init_fn: unsafe extern "C" fn(
+            lvgl_sys::lv_color_t,
+            lvgl_sys::lv_color_t,
+            u32,
+            *const lvgl_sys::lv_font_t,
+            *const lvgl_sys::lv_font_t,
+            *const lvgl_sys::lv_font_t,
+            *const lvgl_sys::lv_font_t,
+        ) -> *mut lvgl_sys::lv_theme_t,
+        config: &ThemeConfig,
+    ) -> LvResult<Self> {
+        crate::lvgl_init();
+        let raw = unsafe {
+            init_fn(
+                config.primary.raw,
+                config.secondary.raw,
+                config.flags.bits(),
+                config.font_small.raw(),
+                config.font_normal.raw(),
+                config.font_subtitle.raw(),
+                config.font_title.raw(),
+            )
+        };
+        Ok(Self {
+            raw: NonNull::new(raw).ok_or(LvError::InvalidReference)?,
+        })
+    }
+
+    pub(crate) fn raw(&self) -> *mut lvgl_sys::lv_theme_t {
+        self.raw.as_ptr()
+    }
+}
+
+/// Colors, fonts and flags used to initialize the built-in themes.
+#[derive(Clone)]
+pub struct ThemeConfig {
+    pub primary: Color,
+    pub secondary: Color,
+    pub flags: ThemeFlags,
+    pub font_small: Font,
+    pub font_normal: Font,
+    pub font_subtitle: Font,
+    pub font_title: Font,
+}
+
+impl ThemeConfig {
+    /// The configuration of the active theme, by default the one defined in `lv_conf.h`.
+    pub fn from_active() -> LvResult<Self> {
+        crate::lvgl_init();
+        unsafe {
+            Ok(Self {
+                primary: Color::from_raw(lvgl_sys::lv_theme_get_color_primary()),
+                secondary: Color::from_raw(lvgl_sys::lv_theme_get_color_secondary()),
+                flags: ThemeFlags::from_bits_truncate(lvgl_sys::lv_theme_get_flags()),
+                font_small: font_from_ptr(lvgl_sys::lv_theme_get_font_small())?,
+                font_normal: font_from_ptr(lvgl_sys::lv_theme_get_font_normal())?,
+                font_subtitle: font_from_ptr(lvgl_sys::lv_theme_get_font_subtitle())?,
+                font_title: font_from_ptr(lvgl_sys::lv_theme_get_font_title())?,
+            })
+        }
+    }
+}
+
+// Theme fonts are declared statically in C.
+unsafe fn font_from_ptr(font: *const lvgl_sys::lv_font_t) -> LvResult<Font> {
+    font.as_ref()
+        .map(Font::from_raw)
+        .ok_or(LvError::InvalidReference)
+}
+
+bitflags! {
+    /// Flags understood by the material theme.
+    pub struct ThemeFlags: u32 {
+        const DARK = lvgl_sys::lv_theme_material_flag_t_LV_THEME_MATERIAL_FLAG_DARK;
+        const LIGHT = lvgl_sys::lv_theme_material_flag_t_LV_THEME_MATERIAL_FLAG_LIGHT;
+        const NO_TRANSITION = lvgl_sys::lv_theme_material_flag_t_LV_THEME_MATERIAL_FLAG_NO_TRANSITION;
+        const NO_FOCUS = lvgl_sys::lv_theme_material_flag_t_LV_THEME_MATERIAL_FLAG_NO_FOCUS;
+    }
+}
+
+/// Styles defined in Rust, applied to new objects on top of a base theme.
+///
+/// ```no_run
+/// use lvgl::style::Style;
+/// use lvgl::theme::{CustomTheme, ThemeClass, ThemedObj};
+/// use lvgl::Part;
+///
+/// struct RoundButtons {
+///     button: Style,
+/// }
+///
+/// impl CustomTheme for RoundButtons {
+///     fn apply<'a>(&'a self, obj: &mut ThemedObj<'a>, class: ThemeClass) {
+///         if let ThemeClass::Btn = class {
+///             obj.add_style(Part::Main, &self.button).unwrap();
+///         }
+///     }
+/// }
+/// ```
+pub trait CustomTheme: 'static {
+    /// Called for every new object, after the styles of the base theme were applied. The theme
+    /// is never released, so its styles can be added to the object.
+    fn apply<'a>(&'a self, obj: &mut ThemedObj<'a>, class: ThemeClass);
+}
+
+/// An object being styled by a custom theme.
+pub struct ThemedObj<'a> {
+    obj: Obj,
+    _theme: PhantomData<&'a ()>,
+}
+
+impl<'a> ThemedObj<'a> {
+    /// Add a style owned by the theme to the object.
+    pub fn add_style<P>(&mut self, part: P, style: &'a Style) -> LvResult<()>
+    where
+        P: Into<u8>,
+    {
+        unsafe {
+            lvgl_sys::lv_obj_add_style(
+                self.obj.raw()?.as_mut(),
+                part.into(),
+                &*style.raw as *const _ as *mut _,
+            );
+        }
+        Ok(())
+    }
+
+    /// The object being styled.
+    pub fn obj(&mut self) -> &mut Obj {
+        &mut self.obj
+    }
+}
+
+struct CustomThemeData<T> {
+    // `user_data` points to `custom`
+    raw: lvgl_sys::lv_theme_t,
+    custom: T,
+}
+
+unsafe extern "C" fn apply_callback<T>(
+    theme: *mut lvgl_sys::lv_theme_t,
+    obj: *mut lvgl_sys::lv_obj_t,
+    name: lvgl_sys::lv_theme_style_t,
+) where
+    T: CustomTheme,
+{
+    // LVGL calls every theme of the chain with the active theme, look for the one this
+    // callback was registered with.
+    let mut theme = theme;
+    while let Some(th) = theme.as_ref() {
+        if th.apply_cb.map(|cb| cb as usize) == Some(apply_callback::<T> as usize) {
+            break;
+        }
+        theme = th.base;
+    }
+    if let (Some(theme), Some(obj)) = (theme.as_ref(), NonNull::new(obj)) {
+        let custom = &*(theme.user_data as *const T);
+        let mut themed = ThemedObj {
+            obj: Obj::from_raw(obj),
+            _theme: PhantomData,
+        };
+        custom.apply(&mut themed, name.into());
+    }
+}
+
+/// The type of object a theme is applied to.
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub enum ThemeClass {
+    None,
+    Scr,
+    Obj,
+    Arc,
+    Bar,
+    Btn,
+    Btnmatrix,
+    Calendar,
+    Canvas,
+    Checkbox,
+    Chart,
+    Cont,
+    Cpicker,
+    Dropdown,
+    Gauge,
+    Image,
+    Imgbtn,
+    Keyboard,
+    Label,
+    Led,
+    Line,
+    List,
+    ListBtn,
+    Linemeter,
+    Msgbox,
+    MsgboxBtns,
+    Objmask,
+    Page,
+    Roller,
+    Slider,
+    Spinbox,
+    SpinboxBtn,
+    Spinner,
+    Switch,
+    Table,
+    Tabview,
+    TabviewPage,
+    Textarea,
+    TextareaOneline,
+    Tileview,
+    Win,
+    WinBtn,
+    /// A class defined by the application, starting at `LV_THEME_CUSTOM_START`.
+    Custom(u32),
+}
+
+impl From<lvgl_sys::lv_theme_style_t> for ThemeClass {
+    fn from(name: lvgl_sys::lv_theme_style_t) -> Self {
+        match name {
+            lvgl_sys::lv_theme_style_t_LV_THEME_SCR => ThemeClass::Scr,
+            lvgl_sys::lv_theme_style_t_LV_THEME_OBJ => ThemeClass::Obj,
+            lvgl_sys::lv_theme_style_t_LV_THEME_ARC => ThemeClass::Arc,
+            lvgl_sys::lv_theme_style_t_LV_THEME_BAR => ThemeClass::Bar,
+            lvgl_sys::lv_theme_style_t_LV_THEME_BTN => ThemeClass::Btn,
+            lvgl_sys::lv_theme_style_t_LV_THEME_BTNMATRIX => ThemeClass::Btnmatrix,
+            lvgl_sys::lv_theme_style_t_LV_THEME_CALENDAR => ThemeClass::Calendar,
+            lvgl_sys::lv_theme_style_t_LV_THEME_CANVAS => ThemeClass::Canvas,
+            lvgl_sys::lv_theme_style_t_LV_THEME_CHECKBOX => ThemeClass::Checkbox,
+            lvgl_sys::lv_theme_style_t_LV_THEME_CHART => ThemeClass::Chart,
+            lvgl_sys::lv_theme_style_t_LV_THEME_CONT => ThemeClass::Cont,
+            lvgl_sys::lv_theme_style_t_LV_THEME_CPICKER => ThemeClass::Cpicker,
+            lvgl_sys::lv_theme_style_t_LV_THEME_DROPDOWN => ThemeClass::Dropdown,
+            lvgl_sys::lv_theme_style_t_LV_THEME_GAUGE => ThemeClass::Gauge,
+            lvgl_sys::lv_theme_style_t_LV_THEME_IMAGE => ThemeClass::Image,
+            lvgl_sys::lv_theme_style_t_LV_THEME_IMGBTN => ThemeClass::Imgbtn,
+            lvgl_sys::lv_theme_style_t_LV_THEME_KEYBOARD => ThemeClass::Keyboard,
+            lvgl_sys::lv_theme_style_t_LV_THEME_LABEL => ThemeClass::Label,
+            lvgl_sys::lv_theme_style_t_LV_THEME_LED => ThemeClass::Led,
+            lvgl_sys::lv_theme_style_t_LV_THEME_LINE => ThemeClass::Line,
+            lvgl_sys::lv_theme_style_t_LV_THEME_LIST => ThemeClass::List,
+            lvgl_sys::lv_theme_style_t_LV_THEME_LIST_BTN => ThemeClass::ListBtn,
+            lvgl_sys::lv_theme_style_t_LV_THEME_LINEMETER => ThemeClass::Linemeter,
+            lvgl_sys::lv_theme_style_t_LV_THEME_MSGBOX => ThemeClass::Msgbox,
+            lvgl_sys::lv_theme_style_t_LV_THEME_MSGBOX_BTNS => ThemeClass::MsgboxBtns,
+            lvgl_sys::lv_theme_style_t_LV_THEME_OBJMASK => ThemeClass::Objmask,
+            lvgl_sys::lv_theme_style_t_LV_THEME_PAGE => ThemeClass::Page,
+            lvgl_sys::lv_theme_style_t_LV_THEME_ROLLER => ThemeClass::Roller,
+            lvgl_sys::lv_theme_style_t_LV_THEME_SLIDER => ThemeClass::Slider,
+            lvgl_sys::lv_theme_style_t_LV_THEME_SPINBOX => ThemeClass::Spinbox,
+            lvgl_sys::lv_theme_style_t_LV_THEME_SPINBOX_BTN => ThemeClass::SpinboxBtn,
+            lvgl_sys::lv_theme_style_t_LV_THEME_SPINNER => ThemeClass::Spinner,
+            lvgl_sys::lv_theme_style_t_LV_THEME_SWITCH => ThemeClass::Switch,
+            lvgl_sys::lv_theme_style_t_LV_THEME_TABLE => ThemeClass::Table,
+            lvgl_sys::lv_theme_style_t_LV_THEME_TABVIEW => ThemeClass::Tabview,
+            lvgl_sys::lv_theme_style_t_LV_THEME_TABVIEW_PAGE => ThemeClass::TabviewPage,
+            lvgl_sys::lv_theme_style_t_LV_THEME_TEXTAREA => ThemeClass::Textarea,
+            lvgl_sys::lv_theme_style_t_LV_THEME_TEXTAREA_ONELINE => ThemeClass::TextareaOneline,
+            lvgl_sys::lv_theme_style_t_LV_THEME_TILEVIEW => ThemeClass::Tileview,
+            lvgl_sys::lv_theme_style_t_LV_THEME_WIN => ThemeClass::Win,
+            lvgl_sys::lv_theme_style_t_LV_THEME_WIN_BTN => ThemeClass::WinBtn,
+            lvgl_sys::lv_theme_style_t_LV_THEME_NONE => ThemeClass::None,
+            other => ThemeClass::Custom(other),
+        }
+    }
+}
@@ -1,6 +1,6 @@
 use core::mem;
 use core::ops::{Deref, DerefMut};
-use core::ptr::NonNull;
+use core::ptr::{self, NonNull};
 
 /// Places a sized `T` into LVGL memory.
 ///
@@ -39,11 +39,23 @@ impl<T> Box<T> {
         let b = mem::ManuallyDrop::new(self);
         b.0.as_ptr()
     }
+
+    /// Take back the ownership of a value placed in LVGL memory by `Box::into_raw`.
+    ///
+    /// # Safety
+    /// The pointer must have been returned by `Box::into_raw` and can only be used once.
+    pub unsafe fn from_raw(raw: *mut T) -> Box<T> {
+        Box(NonNull::new_unchecked(raw))
+    }
 }
 
+// Like `alloc::boxed::Box`, which is used instead with the `lvgl_alloc` feature, the value is
+// dropped before its memory is released. Values owning other LVGL memory, e.g. the closures of
+// event handlers or styles, release it then.
 impl<T> Drop for Box<T> {
     fn drop(&mut self) {
         unsafe {
+            ptr::drop_in_place(self.0.as_ptr());
             lvgl_sys::lv_mem_free(self.0.as_ptr() as *const cty::c_void);
         }
     }
@@ -72,6 +84,7 @@ impl<T> AsMut<T> for Box<T> {
 #[cfg(test)]
 mod test {
     use super::*;
+    use core::cell::Cell;
     use std::vec::Vec;
 
     #[test]
@@ -84,6 +97,28 @@ mod test {
         drop(v);
     }
 
+    #[test]
+    fn drop_value_once() {
+        crate::lvgl_init();
+
+        struct Counted<'a>(&'a Cell<usize>);
+
+        impl Drop for Counted<'_> {
+            fn drop(&mut self) {
+                self.0.set(self.0.get() + 1);
+            }
+        }
+
+        let drops = Cell::new(0);
+        drop(Box::new(Counted(&drops)));
+        assert_eq!(drops.get(), 1);
+
+        let raw = Box::into_raw(Box::new(Counted(&drops)));
+        assert_eq!(drops.get(), 1);
+        drop(unsafe { Box::from_raw(raw) });
+        assert_eq!(drops.get(), 2);
+    }
+
     #[test]
     fn place_complex_value_in_lv_mem() {
         crate::lvgl_init();
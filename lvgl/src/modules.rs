@@ -0,0 +1,32 @@
+//! Non-widget LVGL modules, e.g. input groups, displays, input devices and tasks.
+//!
+//! The wrappers are generated from the LVGL bindings, each module has a handle type with the
+//! functions of the module as methods.
+
+macro_rules! define_module {
+    ($item:ident, $native:ty) => {
+        /// A handle to an object managed by LVGL.
+        pub struct $item {
+            raw: core::ptr::NonNull<$native>,
+        }
+
+        impl $item {
+            /// Construct a handle from a raw pointer.
+            ///
+            /// # Safety
+            /// The pointer must point to a valid object, which LVGL keeps alive for as long as
+            /// the handle is used.
+            pub unsafe fn from_raw(raw: core::ptr::NonNull<$native>) -> Self {
+                Self { raw }
+            }
+
+            pub(crate) fn raw(&self) -> *mut $native {
+                self.raw.as_ptr()
+            }
+        }
+    };
+}
+
+use crate::{Anim, Font, NativeObject};
+
+include!(concat!(env!("OUT_DIR"), "/generated_modules.rs"));
@@ -1,7 +1,7 @@
 use crate::Widget;
 use core::convert::{TryFrom, TryInto};
 use core::ptr::NonNull;
-use embedded_graphics::pixelcolor::{Rgb565, Rgb888};
+use embedded_graphics::pixelcolor::{Rgb565, Rgb888, RgbColor};
 
 pub type LvResult<T> = Result<T, LvError>;
 
@@ -11,13 +11,40 @@ pub enum LvError {
     Uninitialized,
     LvOOMemory,
     AlreadyInUse,
+    /// More items than LVGL can hold
+    CapacityExceeded,
 }
 
 #[derive(Clone)]
+#[repr(transparent)]
 pub struct Color {
     pub(crate) raw: lvgl_sys::lv_color_t,
 }
 
+/// A point on the display, with the same layout as `lv_point_t`, so slices of points can be
+/// passed to LVGL, e.g. as the points of a `Line`.
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq)]
+#[repr(C)]
+pub struct Point {
+    pub x: i16,
+    pub y: i16,
+}
+
+impl Point {
+    pub const fn new(x: i16, y: i16) -> Self {
+        Self { x, y }
+    }
+}
+
+impl From<Point> for lvgl_sys::lv_point_t {
+    fn from(point: Point) -> Self {
+        lvgl_sys::lv_point_t {
+            x: point.x,
+            y: point.y,
+        }
+    }
+}
+
 impl Color {
     pub fn from_rgb((r, g, b): (u8, u8, u8)) -> Self {
         let raw = unsafe { lvgl_sys::_LV_COLOR_MAKE(r, g, b) };
@@ -41,6 +68,24 @@ impl Color {
     }
 }
 
+impl From<Color> for lvgl_sys::lv_color_t {
+    fn from(color: Color) -> Self {
+        color.raw
+    }
+}
+
+impl From<Rgb888> for Color {
+    fn from(color: Rgb888) -> Self {
+        Color::from_rgb((color.r(), color.g(), color.b()))
+    }
+}
+
+impl From<Rgb565> for Color {
+    fn from(color: Rgb565) -> Self {
+        Rgb888::from(color).into()
+    }
+}
+
 impl From<Color> for Rgb888 {
     fn from(color: Color) -> Self {
         unsafe {
@@ -158,6 +203,43 @@ pub enum PointerEvent {
     DragThrowBegin,
 }
 
+/// The events of a widget type which are not common to all objects, carried by
+/// `Event::Special`.
+pub trait WidgetEvent: Sized {
+    /// Convert a native event of `obj`, `None` if it is not an event of this type.
+    ///
+    /// # Safety
+    /// Must only be called while LVGL sends `event` to `obj`, e.g. the event data of
+    /// `lv_event_get_data` is only valid at that time.
+    unsafe fn from_raw(
+        obj: NonNull<lvgl_sys::lv_obj_t>,
+        event: lvgl_sys::lv_event_t,
+    ) -> Option<Self>;
+}
+
+impl WidgetEvent for () {
+    unsafe fn from_raw(
+        _obj: NonNull<lvgl_sys::lv_obj_t>,
+        _event: lvgl_sys::lv_event_t,
+    ) -> Option<Self> {
+        None
+    }
+}
+
+/// The index sent with `LV_EVENT_VALUE_CHANGED` by some widgets, e.g. the index of a button,
+/// as a `uint32_t`.
+///
+/// # Safety
+/// Must only be called while LVGL sends such an event.
+pub(crate) unsafe fn event_index() -> Option<u16> {
+    let index = lvgl_sys::lv_event_get_data() as *const u32;
+    if index.is_null() {
+        None
+    } else {
+        Some(*index as u16)
+    }
+}
+
 pub(crate) unsafe extern "C" fn event_callback<T, F>(
     obj: *mut lvgl_sys::lv_obj_t,
     event: lvgl_sys::lv_event_t,
@@ -165,15 +247,20 @@ pub(crate) unsafe extern "C" fn event_callback<T, F>(
     T: Widget + Sized,
     F: FnMut(T, Event<T::SpecialEvent>),
 {
-    // convert the lv_event_t to lvgl-rs Event type
-    if let Ok(event) = event.try_into() {
-        if let Some(obj_ptr) = NonNull::new(obj) {
-            let object = T::from_raw(obj_ptr);
-            // get the pointer from the Rust callback closure FnMut provided by users
-            let user_closure = &mut *((*obj).user_data as *mut F);
-            // call user callback closure
-            user_closure(object, event);
-        }
+    if let Some(obj_ptr) = NonNull::new(obj) {
+        // convert the lv_event_t to lvgl-rs Event type
+        let event = match event.try_into() {
+            Ok(event) => event,
+            Err(_) => match <T::SpecialEvent as WidgetEvent>::from_raw(obj_ptr, event) {
+                Some(special) => Event::Special(special),
+                None => return,
+            },
+        };
+        let object = T::from_raw(obj_ptr);
+        // get the pointer from the Rust callback closure FnMut provided by users
+        let user_closure = &mut *((*obj).user_data as *mut F);
+        // call user callback closure
+        user_closure(object, event);
     }
 }
 
@@ -244,6 +331,50 @@ impl From<Animation> for lvgl_sys::lv_anim_enable_t {
     }
 }
 
+/// Describes how the value of an animation changes over time (the easing function).
+#[repr(transparent)]
+pub struct AnimPath {
+    raw: lvgl_sys::lv_anim_path_t,
+}
+
+// The built-in paths are immutable and do not have any user data.
+unsafe impl Sync for AnimPath {}
+
+impl AnimPath {
+    /// Linear change of the value
+    pub const LINEAR: AnimPath = AnimPath::from_cb(lvgl_sys::lv_anim_path_linear);
+    /// Slower at the beginning
+    pub const EASE_IN: AnimPath = AnimPath::from_cb(lvgl_sys::lv_anim_path_ease_in);
+    /// Slower at the end
+    pub const EASE_OUT: AnimPath = AnimPath::from_cb(lvgl_sys::lv_anim_path_ease_out);
+    /// Slower at the beginning and at the end
+    pub const EASE_IN_OUT: AnimPath = AnimPath::from_cb(lvgl_sys::lv_anim_path_ease_in_out);
+    /// Overshoot the end value
+    pub const OVERSHOOT: AnimPath = AnimPath::from_cb(lvgl_sys::lv_anim_path_overshoot);
+    /// Bounce back a little from the end value (like hitting a wall)
+    pub const BOUNCE: AnimPath = AnimPath::from_cb(lvgl_sys::lv_anim_path_bounce);
+    /// Set the end value in the final step
+    pub const STEP: AnimPath = AnimPath::from_cb(lvgl_sys::lv_anim_path_step);
+
+    const fn from_cb(
+        cb: unsafe extern "C" fn(
+            *const lvgl_sys::lv_anim_path_t,
+            *const lvgl_sys::lv_anim_t,
+        ) -> lvgl_sys::lv_anim_value_t,
+    ) -> Self {
+        Self {
+            raw: lvgl_sys::lv_anim_path_t {
+                cb: Some(cb),
+                user_data: core::ptr::null_mut(),
+            },
+        }
+    }
+
+    pub(crate) fn raw(&self) -> *const lvgl_sys::lv_anim_path_t {
+        &self.raw as *const _
+    }
+}
+
 #[cfg(test)]
 mod test {
     use super::*;
@@ -1,3 +1,4 @@
+use crate::theme::Theme;
 use crate::Box;
 use crate::{Color, Event, LvError, LvResult, Obj, Widget};
 use core::marker::PhantomData;
@@ -110,6 +111,13 @@ where
         }
     }
 
+    /// Set the theme used to style the objects created from now on.
+    pub fn set_theme(&mut self, theme: Theme) {
+        unsafe {
+            lvgl_sys::lv_theme_set_act(theme.raw());
+        }
+    }
+
     pub fn event_send<W>(&mut self, obj: &mut W, event: Event<W::SpecialEvent>) -> LvResult<()>
     where
         W: Widget,
@@ -0,0 +1,436 @@
+use crate::support::event_index;
+use crate::widgets::Btnmatrix;
+use crate::{LvError, LvResult, NativeObject, Widget, WidgetEvent};
+use core::mem;
+use core::ptr::{self, NonNull};
+use cstr_core::CStr;
+
+// The row separator and the end of a map, which are not allocated.
+static NEW_ROW: [u8; 2] = *b"\n\0";
+static END: [u8; 1] = *b"\0";
+
+// The signal handler of the button matrix type, called before the map of a button matrix is
+// released. LVGL runs on a single thread and all button matrices have the same handler.
+static mut ANCESTOR_SIGNAL: lvgl_sys::lv_signal_cb_t = None;
+
+bitflags! {
+    /// How a button of a `ButtonMap` behaves.
+    pub struct ButtonCtrl: u16 {
+        const HIDDEN = lvgl_sys::LV_BTNMATRIX_CTRL_HIDDEN as u16;
+        /// Do not repeat the press when the button is long pressed
+        const NO_REPEAT = lvgl_sys::LV_BTNMATRIX_CTRL_NO_REPEAT as u16;
+        const DISABLED = lvgl_sys::LV_BTNMATRIX_CTRL_DISABLED as u16;
+        /// The button can be toggled
+        const CHECKABLE = lvgl_sys::LV_BTNMATRIX_CTRL_CHECKABLE as u16;
+        /// The button is toggled
+        const CHECK_STATE = lvgl_sys::LV_BTNMATRIX_CTRL_CHECK_STATE as u16;
+        /// Send `LV_EVENT_VALUE_CHANGED` on click instead of press
+        const CLICK_TRIG = lvgl_sys::LV_BTNMATRIX_CTRL_CLICK_TRIG as u16;
+    }
+}
+
+/// The events of a `Btnmatrix`, in `Event::Special`.
+pub enum BtnmatrixEvent {
+    /// A button was pressed, or clicked with `ButtonCtrl::CLICK_TRIG`, with the index of the
+    /// button in the map
+    ValueChanged(u16),
+}
+
+impl WidgetEvent for BtnmatrixEvent {
+    unsafe fn from_raw(
+        _obj: NonNull<lvgl_sys::lv_obj_t>,
+        event: lvgl_sys::lv_event_t,
+    ) -> Option<Self> {
+        match u32::from(event) {
+            lvgl_sys::LV_EVENT_VALUE_CHANGED => event_index().map(BtnmatrixEvent::ValueChanged),
+            _ => None,
+        }
+    }
+}
+
+impl Btnmatrix {
+    /// Create a button matrix with the map and the control flags of `copy`. A map set with
+    /// `set_map` is copied, each button matrix releases its own.
+    pub fn new_copy<C>(parent: &mut C, copy: &Self) -> LvResult<Self>
+    where
+        C: NativeObject,
+    {
+        unsafe {
+            let source = copy.raw()?.as_ptr();
+            let ptr = lvgl_sys::lv_btnmatrix_create(parent.raw()?.as_mut(), source);
+            let raw = NonNull::new(ptr).ok_or(LvError::InvalidReference)?;
+            if owns_map(source) {
+                // LVGL copies the pointer to the map, which is released with `copy`. The copy
+                // of the map has the same buttons, so the control flags are kept.
+                let map = lvgl_sys::lv_btnmatrix_get_map_array(source);
+                match ButtonMap::copy_of(map) {
+                    Ok(map) => {
+                        lvgl_sys::lv_btnmatrix_set_map(ptr, map.into_map());
+                        own_map(ptr);
+                    }
+                    Err(err) => {
+                        lvgl_sys::lv_obj_del(ptr);
+                        return Err(err);
+                    }
+                }
+            }
+            Ok(Self::from_raw(raw))
+        }
+    }
+
+    /// Show the buttons of `map`, which is released with the button matrix.
+    pub fn set_map(&mut self, map: ButtonMap) -> LvResult<()> {
+        let (texts, ctrl) = map.as_raw()?;
+        unsafe {
+            let raw = self.core.raw()?.as_ptr();
+            // The signal handler is replaced when a first map is set, the map set before is
+            // the default one of LVGL
+            let owned = owns_map(raw);
+            let previous = lvgl_sys::lv_btnmatrix_get_map_array(raw) as *mut *const cty::c_char;
+            lvgl_sys::lv_btnmatrix_set_map(raw, texts);
+            lvgl_sys::lv_btnmatrix_set_ctrl_map(raw, ctrl);
+            if owned {
+                free_map(previous);
+            } else {
+                own_map(raw);
+            }
+        }
+        map.into_map();
+        Ok(())
+    }
+
+    /// Set control flags of a button, the other flags of the button are kept.
+    pub fn set_btn_ctrl(&mut self, id: u16, ctrl: ButtonCtrl) -> LvResult<()> {
+        unsafe {
+            lvgl_sys::lv_btnmatrix_set_btn_ctrl(self.core.raw()?.as_mut(), id, ctrl.bits());
+        }
+        Ok(())
+    }
+
+    /// Clear control flags of a button.
+    pub fn clear_btn_ctrl(&mut self, id: u16, ctrl: ButtonCtrl) -> LvResult<()> {
+        unsafe {
+            lvgl_sys::lv_btnmatrix_clear_btn_ctrl(self.core.raw()?.as_mut(), id, ctrl.bits());
+        }
+        Ok(())
+    }
+
+    /// Set control flags of all the buttons.
+    pub fn set_btn_ctrl_all(&mut self, ctrl: ButtonCtrl) -> LvResult<()> {
+        unsafe {
+            lvgl_sys::lv_btnmatrix_set_btn_ctrl_all(self.core.raw()?.as_mut(), ctrl.bits());
+        }
+        Ok(())
+    }
+
+    /// Clear control flags of all the buttons.
+    pub fn clear_btn_ctrl_all(&mut self, ctrl: ButtonCtrl) -> LvResult<()> {
+        unsafe {
+            lvgl_sys::lv_btnmatrix_clear_btn_ctrl_all(self.core.raw()?.as_mut(), ctrl.bits());
+        }
+        Ok(())
+    }
+
+    /// Whether a button has any of the given control flags.
+    pub fn has_btn_ctrl(&self, id: u16, ctrl: ButtonCtrl) -> LvResult<bool> {
+        unsafe {
+            Ok(lvgl_sys::lv_btnmatrix_get_btn_ctrl(
+                self.core.raw()?.as_mut(),
+                id,
+                ctrl.bits(),
+            ))
+        }
+    }
+}
+
+/// The rows of buttons of a button matrix or a keyboard, with their text, relative width and
+/// control flags. The texts are copied to LVGL memory.
+///
+/// ```ignore
+/// let map = ButtonMap::new()
+///     .button(cstr!("1"), 1, ButtonCtrl::empty())?
+///     .button(cstr!("2"), 1, ButtonCtrl::empty())?
+///     .row()?
+///     .button(cstr!("OK"), 2, ButtonCtrl::NO_REPEAT)?;
+/// ```
+pub struct ButtonMap {
+    // The texts of the buttons, with the `"\n"` row separators and ended by `""`, the format of
+    // `lv_btnmatrix_set_map`. It has `len + 1` elements once a button is added.
+    map: *mut *const cty::c_char,
+    len: usize,
+    // The control bits of each button, there are none for the row separators
+    ctrl: *mut lvgl_sys::lv_btnmatrix_ctrl_t,
+    buttons: usize,
+}
+
+impl ButtonMap {
+    pub fn new() -> Self {
+        Self {
+            map: ptr::null_mut(),
+            len: 0,
+            ctrl: ptr::null_mut(),
+            buttons: 0,
+        }
+    }
+
+    /// Add a button to the current row. Its `width` is relative to the other buttons of the
+    /// row, from 1 to 7.
+    pub fn button(mut self, text: &CStr, width: u8, ctrl: ButtonCtrl) -> LvResult<Self> {
+        if width == 0 || u32::from(width) > lvgl_sys::LV_BTNMATRIX_WIDTH_MASK {
+            return Err(LvError::InvalidReference);
+        }
+        self.push_copy(text)?;
+        unsafe {
+            let ctrl_size = (self.buttons + 1) * mem::size_of::<lvgl_sys::lv_btnmatrix_ctrl_t>();
+            let ctrl_array = lvgl_sys::lv_mem_realloc(
+                self.ctrl as *mut cty::c_void,
+                ctrl_size as lvgl_sys::size_t,
+            ) as *mut lvgl_sys::lv_btnmatrix_ctrl_t;
+            if ctrl_array.is_null() {
+                // The text is freed with the map
+                return Err(LvError::LvOOMemory);
+            }
+            ctrl_array
+                .add(self.buttons)
+                .write(ctrl.bits() | u16::from(width));
+            self.ctrl = ctrl_array;
+            self.buttons += 1;
+        }
+        Ok(self)
+    }
+
+    /// Start a new row of buttons.
+    pub fn row(mut self) -> LvResult<Self> {
+        self.push_text(NEW_ROW.as_ptr() as *const cty::c_char)?;
+        Ok(self)
+    }
+
+    /// The number of buttons.
+    pub fn len(&self) -> usize {
+        self.buttons
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.buttons == 0
+    }
+
+    /// A copy of a map built by `ButtonMap`, with the texts of its buttons but without control
+    /// bits.
+    unsafe fn copy_of(map: *const *const cty::c_char) -> LvResult<Self> {
+        let mut copy = Self::new();
+        let mut text = map;
+        while *text != END.as_ptr() as *const cty::c_char {
+            if *text == NEW_ROW.as_ptr() as *const cty::c_char {
+                copy.push_text(*text)?;
+            } else {
+                copy.push_copy(CStr::from_ptr(*text))?;
+            }
+            text = text.add(1);
+        }
+        Ok(copy)
+    }
+
+    /// Add a copy of `text` in LVGL memory before the end of the map.
+    fn push_copy(&mut self, text: &CStr) -> LvResult<()> {
+        let bytes = text.to_bytes_with_nul();
+        unsafe {
+            let copy = lvgl_sys::lv_mem_alloc(bytes.len() as lvgl_sys::size_t) as *mut cty::c_char;
+            if copy.is_null() {
+                return Err(LvError::LvOOMemory);
+            }
+            ptr::copy_nonoverlapping(bytes.as_ptr(), copy as *mut u8, bytes.len());
+            if let Err(err) = self.push_text(copy) {
+                lvgl_sys::lv_mem_free(copy as *const cty::c_void);
+                return Err(err);
+            }
+        }
+        Ok(())
+    }
+
+    /// Add `text` before the end of the map.
+    fn push_text(&mut self, text: *const cty::c_char) -> LvResult<()> {
+        unsafe {
+            let size = (self.len + 2) * mem::size_of::<*const cty::c_char>();
+            let map =
+                lvgl_sys::lv_mem_realloc(self.map as *mut cty::c_void, size as lvgl_sys::size_t)
+                    as *mut *const cty::c_char;
+            if map.is_null() {
+                return Err(LvError::LvOOMemory);
+            }
+            map.add(self.len).write(text);
+            map.add(self.len + 1)
+                .write(END.as_ptr() as *const cty::c_char);
+            self.map = map;
+            self.len += 1;
+        }
+        Ok(())
+    }
+
+    /// Give up the ownership of the map, LVGL copies the control bits, which are freed. The map
+    /// is released with `free_map`.
+    pub(crate) fn into_map(self) -> *mut *const cty::c_char {
+        let map = mem::ManuallyDrop::new(self);
+        unsafe {
+            lvgl_sys::lv_mem_free(map.ctrl as *const cty::c_void);
+        }
+        map.map
+    }
+
+    /// The map and the control bits, which LVGL reads as long as they are used. The map must
+    /// have buttons, and all of them must have control bits.
+    pub(crate) fn as_raw(
+        &self,
+    ) -> LvResult<(
+        *mut *const cty::c_char,
+        *const lvgl_sys::lv_btnmatrix_ctrl_t,
+    )> {
+        if self.is_empty() || self.ctrl.is_null() {
+            return Err(LvError::InvalidReference);
+        }
+        Ok((self.map, self.ctrl))
+    }
+}
+
+impl Default for ButtonMap {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Drop for ButtonMap {
+    fn drop(&mut self) {
+        unsafe {
+            if !self.map.is_null() {
+                free_map(self.map);
+            }
+            if !self.ctrl.is_null() {
+                lvgl_sys::lv_mem_free(self.ctrl as *const cty::c_void);
+            }
+        }
+    }
+}
+
+/// Whether the map of `btnm` was set by `Btnmatrix::set_map` and is released with it.
+unsafe fn owns_map(btnm: *const lvgl_sys::lv_obj_t) -> bool {
+    lvgl_sys::lv_obj_get_signal_cb(btnm).map(|cb| cb as usize) == Some(free_map_signal as usize)
+}
+
+/// Release the map of `btnm` with it.
+unsafe fn own_map(btnm: *mut lvgl_sys::lv_obj_t) {
+    ANCESTOR_SIGNAL = lvgl_sys::lv_obj_get_signal_cb(btnm);
+    lvgl_sys::lv_obj_set_signal_cb(btnm, Some(free_map_signal));
+}
+
+/// Free the texts and the array of a map built by `ButtonMap`.
+unsafe fn free_map(map: *mut *const cty::c_char) {
+    let mut text = map;
+    while *text != END.as_ptr() as *const cty::c_char {
+        if *text != NEW_ROW.as_ptr() as *const cty::c_char {
+            lvgl_sys::lv_mem_free(*text as *const cty::c_void);
+        }
+        text = text.add(1);
+    }
+    lvgl_sys::lv_mem_free(map as *const cty::c_void);
+}
+
+unsafe extern "C" fn free_map_signal(
+    btnm: *mut lvgl_sys::lv_obj_t,
+    sign: lvgl_sys::lv_signal_t,
+    param: *mut cty::c_void,
+) -> lvgl_sys::lv_res_t {
+    let res = match ANCESTOR_SIGNAL {
+        Some(ancestor) => ancestor(btnm, sign, param),
+        None => lvgl_sys::LV_RES_OK as lvgl_sys::lv_res_t,
+    };
+    if u32::from(sign) == lvgl_sys::LV_SIGNAL_CLEANUP {
+        free_map(lvgl_sys::lv_btnmatrix_get_map_array(btnm) as *mut *const cty::c_char);
+    }
+    res
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use core::slice;
+
+    fn cstr(bytes: &[u8]) -> &CStr {
+        CStr::from_bytes_with_nul(bytes).unwrap()
+    }
+
+    // The texts of a map, up to the final empty one
+    fn texts(map: *const *const cty::c_char) -> Vec<&'static [u8]> {
+        let mut texts = Vec::new();
+        unsafe {
+            let mut text = map;
+            loop {
+                let bytes = CStr::from_ptr(*text).to_bytes();
+                texts.push(bytes);
+                if bytes.is_empty() {
+                    return texts;
+                }
+                text = text.add(1);
+            }
+        }
+    }
+
+    #[test]
+    fn button_map_has_rows_and_control_bits() {
+        crate::lvgl_init();
+
+        let map = ButtonMap::new()
+            .button(cstr(b"1\0"), 1, ButtonCtrl::empty())
+            .unwrap()
+            .button(cstr(b"2\0"), 3, ButtonCtrl::CHECKABLE)
+            .unwrap()
+            .row()
+            .unwrap()
+            .button(cstr(b"OK\0"), 7, ButtonCtrl::NO_REPEAT)
+            .unwrap();
+        assert_eq!(map.len(), 3);
+
+        let (texts_ptr, ctrl) = map.as_raw().unwrap();
+        let expected: [&[u8]; 5] = [b"1", b"2", b"\n", b"OK", b""];
+        assert_eq!(texts(texts_ptr), expected);
+        let ctrl = unsafe { slice::from_raw_parts(ctrl, map.len()) };
+        let expected = [
+            1,
+            ButtonCtrl::CHECKABLE.bits() | 3,
+            ButtonCtrl::NO_REPEAT.bits() | 7,
+        ];
+        assert_eq!(ctrl, expected);
+    }
+
+    #[test]
+    fn button_map_owns_copies_of_the_texts() {
+        crate::lvgl_init();
+
+        let mut text = *b"A\0";
+        let map = ButtonMap::new()
+            .button(cstr(&text), 1, ButtonCtrl::empty())
+            .unwrap()
+            .row()
+            .unwrap()
+            .button(cstr(b"B\0"), 1, ButtonCtrl::empty())
+            .unwrap();
+        text[0] = b'C';
+
+        let copy = unsafe { ButtonMap::copy_of(map.as_raw().unwrap().0) }.unwrap();
+        drop(map);
+        let expected: [&[u8]; 4] = [b"A", b"\n", b"B", b""];
+        assert_eq!(texts(copy.map), expected);
+    }
+
+    #[test]
+    fn button_map_rejects_invalid_widths_and_empty_maps() {
+        crate::lvgl_init();
+
+        assert!(ButtonMap::new()
+            .button(cstr(b"1\0"), 0, ButtonCtrl::empty())
+            .is_err());
+        assert!(ButtonMap::new()
+            .button(cstr(b"1\0"), 8, ButtonCtrl::empty())
+            .is_err());
+        assert!(ButtonMap::new().as_raw().is_err());
+        assert!(ButtonMap::new().row().unwrap().as_raw().is_err());
+    }
+}
@@ -0,0 +1,374 @@
+use crate::draw::{DrawImage, DrawLabel, DrawLine, DrawRect};
+use crate::style::Opacity;
+use crate::widgets::{Canvas, LabelAlign};
+use crate::{Color, ColorFormat, ImageSource, LvError, LvResult, NativeObject, Point, Widget};
+use core::ptr::{self, NonNull};
+use cstr_core::CStr;
+use embedded_graphics::drawable::Pixel;
+use embedded_graphics::geometry::Size;
+use embedded_graphics::pixelcolor::PixelColor;
+use embedded_graphics::DrawTarget;
+
+// The signal handler of the canvas type, called before the buffer of a canvas is released.
+// LVGL runs on a single thread and all canvases have the same handler.
+static mut ANCESTOR_SIGNAL: lvgl_sys::lv_signal_cb_t = None;
+
+impl Canvas {
+    /// Create a canvas drawing to a buffer of `width` by `height` pixels in the given format.
+    ///
+    /// The buffer is allocated in LVGL memory, with the size LVGL needs for the format and
+    /// `LV_COLOR_DEPTH`, and it is released when the canvas is deleted.
+    pub fn with_buffer<C>(
+        parent: &mut C,
+        width: i16,
+        height: i16,
+        format: ColorFormat,
+    ) -> LvResult<Self>
+    where
+        C: NativeObject,
+    {
+        if width <= 0 || height <= 0 {
+            return Err(LvError::InvalidReference);
+        }
+        let canvas = Self::new(parent)?;
+        unsafe {
+            let raw = canvas.raw()?.as_ptr();
+            if let Err(err) = alloc_buffer(raw, width, height, format.into()) {
+                lvgl_sys::lv_obj_del(raw);
+                return Err(err);
+            }
+        }
+        Ok(canvas)
+    }
+
+    /// Create a canvas with the attributes of `copy`. The buffer of `copy` is not shared, the
+    /// canvas gets a buffer of the same size and format holding a copy of its pixels.
+    pub fn new_copy<C>(parent: &mut C, copy: &Self) -> LvResult<Self>
+    where
+        C: NativeObject,
+    {
+        unsafe {
+            let source = copy.raw()?.as_ptr();
+            let ptr = lvgl_sys::lv_canvas_create(parent.raw()?.as_mut(), source);
+            let raw = NonNull::new(ptr).ok_or(LvError::InvalidReference)?;
+            // Only the buffer allocated below is released with the canvas
+            let img = lvgl_sys::lv_canvas_get_img(ptr);
+            (*img).data = ptr::null();
+            let src = &*lvgl_sys::lv_canvas_get_img(source);
+            if !src.data.is_null() {
+                let (width, height) = (src.header.w() as i16, src.header.h() as i16);
+                let format = src.header.cf() as lvgl_sys::lv_img_cf_t;
+                match alloc_buffer(ptr, width, height, format) {
+                    Ok(size) => ptr::copy_nonoverlapping(src.data, (*img).data as *mut u8, size),
+                    Err(err) => {
+                        lvgl_sys::lv_obj_del(ptr);
+                        return Err(err);
+                    }
+                }
+            }
+            Ok(Self::from_raw(raw))
+        }
+    }
+
+    /// The size of the buffer, in pixels. It is empty until a buffer is set.
+    pub fn buffer_size(&self) -> LvResult<(i16, i16)> {
+        unsafe {
+            let img = lvgl_sys::lv_canvas_get_img(self.core.raw()?.as_ptr());
+            let header = &(*img).header;
+            Ok((header.w() as i16, header.h() as i16))
+        }
+    }
+
+    fn check_bounds(&self, x: i16, y: i16) -> LvResult<()> {
+        let (width, height) = self.buffer_size()?;
+        if (0..width).contains(&x) && (0..height).contains(&y) {
+            Ok(())
+        } else {
+            Err(LvError::InvalidReference)
+        }
+    }
+
+    /// Set the color of a pixel, fails if it is outside of the buffer.
+    pub fn set_px(&mut self, x: i16, y: i16, color: Color) -> LvResult<()> {
+        self.check_bounds(x, y)?;
+        unsafe {
+            lvgl_sys::lv_canvas_set_px(self.core.raw()?.as_mut(), x, y, color.raw);
+        }
+        Ok(())
+    }
+
+    /// The color of a pixel, fails if it is outside of the buffer.
+    pub fn get_px(&self, x: i16, y: i16) -> LvResult<Color> {
+        self.check_bounds(x, y)?;
+        unsafe {
+            let color = lvgl_sys::lv_canvas_get_px(self.core.raw()?.as_mut(), x, y);
+            Ok(Color::from_raw(color))
+        }
+    }
+
+    pub fn draw_rect(&mut self, x: i16, y: i16, w: i16, h: i16, dsc: &DrawRect) -> LvResult<()> {
+        unsafe {
+            lvgl_sys::lv_canvas_draw_rect(self.core.raw()?.as_mut(), x, y, w, h, &dsc.raw);
+        }
+        Ok(())
+    }
+
+    /// Draw a polyline through `points`.
+    pub fn draw_line(&mut self, points: &[Point], dsc: &DrawLine) -> LvResult<()> {
+        let count = core::convert::TryInto::try_into(points.len())
+            .map_err(|_| LvError::InvalidReference)?;
+        unsafe {
+            lvgl_sys::lv_canvas_draw_line(
+                self.core.raw()?.as_mut(),
+                points.as_ptr() as *const lvgl_sys::lv_point_t,
+                count,
+                &dsc.raw,
+            );
+        }
+        Ok(())
+    }
+
+    pub fn draw_polygon(&mut self, points: &[Point], dsc: &DrawRect) -> LvResult<()> {
+        let count = core::convert::TryInto::try_into(points.len())
+            .map_err(|_| LvError::InvalidReference)?;
+        unsafe {
+            lvgl_sys::lv_canvas_draw_polygon(
+                self.core.raw()?.as_mut(),
+                points.as_ptr() as *const lvgl_sys::lv_point_t,
+                count,
+                &dsc.raw,
+            );
+        }
+        Ok(())
+    }
+
+    /// Draw an arc centered on `(x, y)`, the angles are in degrees, 0 is on the right and 90
+    /// is at the bottom.
+    pub fn draw_arc(
+        &mut self,
+        x: i16,
+        y: i16,
+        radius: i16,
+        start_angle: i32,
+        end_angle: i32,
+        dsc: &DrawLine,
+    ) -> LvResult<()> {
+        unsafe {
+            lvgl_sys::lv_canvas_draw_arc(
+                self.core.raw()?.as_mut(),
+                x,
+                y,
+                radius,
+                start_angle,
+                end_angle,
+                &dsc.raw,
+            );
+        }
+        Ok(())
+    }
+
+    /// Draw a text, wrapped to lines of at most `max_width` pixels.
+    pub fn draw_text(
+        &mut self,
+        x: i16,
+        y: i16,
+        max_width: i16,
+        text: &CStr,
+        align: LabelAlign,
+        dsc: &DrawLabel,
+    ) -> LvResult<()> {
+        // LVGL takes a mutable descriptor, but does not change it
+        let mut raw = dsc.raw;
+        unsafe {
+            lvgl_sys::lv_canvas_draw_text(
+                self.core.raw()?.as_mut(),
+                x,
+                y,
+                max_width,
+                &mut raw,
+                text.as_ptr(),
+                align.into(),
+            );
+        }
+        Ok(())
+    }
+
+    pub fn draw_img(&mut self, x: i16, y: i16, src: ImageSource, dsc: &DrawImage) -> LvResult<()> {
+        unsafe {
+            lvgl_sys::lv_canvas_draw_img(self.core.raw()?.as_mut(), x, y, src.as_ptr(), &dsc.raw);
+        }
+        Ok(())
+    }
+}
+
+/// Draw with `embedded-graphics` on the buffer of the canvas, pixels outside of the buffer
+/// are ignored.
+impl<C> DrawTarget<C> for Canvas
+where
+    C: PixelColor + Into<Color>,
+{
+    type Error = LvError;
+
+    fn draw_pixel(&mut self, Pixel(point, color): Pixel<C>) -> Result<(), Self::Error> {
+        let (width, height) = self.buffer_size()?;
+        if point.x < 0 || point.y < 0 || point.x >= width.into() || point.y >= height.into() {
+            return Ok(());
+        }
+        unsafe {
+            lvgl_sys::lv_canvas_set_px(
+                self.core.raw()?.as_mut(),
+                point.x as i16,
+                point.y as i16,
+                Into::<Color>::into(color).raw,
+            );
+        }
+        Ok(())
+    }
+
+    fn size(&self) -> Size {
+        let (width, height) = self.buffer_size().unwrap_or((0, 0));
+        Size::new(width as u32, height as u32)
+    }
+
+    fn clear(&mut self, color: C) -> Result<(), Self::Error> {
+        unsafe {
+            lvgl_sys::lv_canvas_fill_bg(
+                self.core.raw()?.as_mut(),
+                Into::<Color>::into(color).raw,
+                Opacity::OPA_COVER.into(),
+            );
+        }
+        Ok(())
+    }
+}
+
+/// Allocate a buffer for `canvas`, which is released with it, and return its size in bytes.
+unsafe fn alloc_buffer(
+    canvas: *mut lvgl_sys::lv_obj_t,
+    width: i16,
+    height: i16,
+    format: lvgl_sys::lv_img_cf_t,
+) -> LvResult<usize> {
+    let size = lvgl_sys::lv_img_buf_get_img_size(width, height, format);
+    let buf = lvgl_sys::lv_mem_alloc(size as lvgl_sys::size_t);
+    if buf.is_null() {
+        return Err(LvError::LvOOMemory);
+    }
+    lvgl_sys::lv_canvas_set_buffer(canvas, buf, width, height, format);
+
+    ANCESTOR_SIGNAL = lvgl_sys::lv_obj_get_signal_cb(canvas);
+    lvgl_sys::lv_obj_set_signal_cb(canvas, Some(free_buffer_signal));
+    Ok(size as usize)
+}
+
+unsafe extern "C" fn free_buffer_signal(
+    canvas: *mut lvgl_sys::lv_obj_t,
+    sign: lvgl_sys::lv_signal_t,
+    param: *mut cty::c_void,
+) -> lvgl_sys::lv_res_t {
+    let res = match ANCESTOR_SIGNAL {
+        Some(ancestor) => ancestor(canvas, sign, param),
+        None => lvgl_sys::LV_RES_OK as lvgl_sys::lv_res_t,
+    };
+    if u32::from(sign) == lvgl_sys::LV_SIGNAL_CLEANUP {
+        let img = lvgl_sys::lv_canvas_get_img(canvas);
+        lvgl_sys::lv_mem_free((*img).data as *const cty::c_void);
+    }
+    res
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::Obj;
+    use embedded_graphics::drawable::Drawable;
+    use embedded_graphics::geometry::Point as EgPoint;
+    use embedded_graphics::pixelcolor::{Rgb888, RgbColor};
+
+    fn same(a: Color, b: Color) -> bool {
+        (a.r(), a.g(), a.b()) == (b.r(), b.g(), b.b())
+    }
+
+    #[test]
+    fn buffer_has_the_canvas_size() {
+        crate::lvgl_init();
+        let mut screen = Obj::default();
+        let canvas = Canvas::with_buffer(&mut screen, 20, 10, ColorFormat::TrueColor).unwrap();
+        assert_eq!(canvas.buffer_size(), Ok((20, 10)));
+        assert_eq!(DrawTarget::<Rgb888>::size(&canvas), Size::new(20, 10));
+
+        let canvas = Canvas::new(&mut screen).unwrap();
+        assert_eq!(canvas.buffer_size(), Ok((0, 0)));
+    }
+
+    #[test]
+    fn empty_buffer_is_rejected() {
+        crate::lvgl_init();
+        let mut screen = Obj::default();
+        assert!(Canvas::with_buffer(&mut screen, 0, 10, ColorFormat::TrueColor).is_err());
+        assert!(Canvas::with_buffer(&mut screen, 10, -1, ColorFormat::TrueColor).is_err());
+    }
+
+    #[test]
+    fn pixels_outside_of_the_buffer_are_rejected() {
+        crate::lvgl_init();
+        let mut screen = Obj::default();
+        let mut canvas = Canvas::with_buffer(&mut screen, 4, 3, ColorFormat::TrueColor).unwrap();
+        let red = Color::from_rgb((255, 0, 0));
+
+        canvas.set_px(3, 2, red).unwrap();
+        assert!(same(canvas.get_px(3, 2).unwrap(), red));
+        assert!(canvas.set_px(4, 0, red).is_err());
+        assert!(canvas.set_px(0, 3, red).is_err());
+        assert!(canvas.set_px(-1, 0, red).is_err());
+        assert!(canvas.get_px(4, 0).is_err());
+        assert!(canvas.get_px(0, -1).is_err());
+
+        let mut empty = Canvas::new(&mut screen).unwrap();
+        assert!(empty.set_px(0, 0, red).is_err());
+    }
+
+    #[test]
+    fn draw_target_ignores_pixels_outside_of_the_buffer() {
+        crate::lvgl_init();
+        let mut screen = Obj::default();
+        let mut canvas = Canvas::with_buffer(&mut screen, 4, 4, ColorFormat::TrueColor).unwrap();
+
+        canvas.clear(Rgb888::BLACK).unwrap();
+        Pixel(EgPoint::new(1, 2), Rgb888::RED)
+            .draw(&mut canvas)
+            .unwrap();
+        Pixel(EgPoint::new(4, 0), Rgb888::RED)
+            .draw(&mut canvas)
+            .unwrap();
+        Pixel(EgPoint::new(-1, 0), Rgb888::RED)
+            .draw(&mut canvas)
+            .unwrap();
+        assert!(same(canvas.get_px(1, 2).unwrap(), Rgb888::RED.into()));
+        assert!(same(canvas.get_px(3, 0).unwrap(), Rgb888::BLACK.into()));
+    }
+
+    #[test]
+    fn copy_has_its_own_buffer() {
+        crate::lvgl_init();
+        let mut screen = Obj::default();
+        let mut canvas = Canvas::with_buffer(&mut screen, 4, 4, ColorFormat::TrueColor).unwrap();
+        let (red, blue) = (Color::from_rgb((255, 0, 0)), Color::from_rgb((0, 0, 255)));
+        canvas.set_px(1, 1, red).unwrap();
+
+        let mut copy = Canvas::new_copy(&mut screen, &canvas).unwrap();
+        assert_eq!(copy.buffer_size(), Ok((4, 4)));
+        assert!(same(copy.get_px(1, 1).unwrap(), red));
+        copy.set_px(1, 1, blue).unwrap();
+        assert!(same(canvas.get_px(1, 1).unwrap(), red));
+
+        unsafe {
+            let data = |canvas: &Canvas| {
+                (*lvgl_sys::lv_canvas_get_img(canvas.raw().unwrap().as_ptr())).data
+            };
+            assert_ne!(data(&canvas), data(&copy));
+            lvgl_sys::lv_obj_del(copy.raw().unwrap().as_ptr());
+            assert!(same(canvas.get_px(1, 1).unwrap(), red));
+        }
+    }
+}
@@ -0,0 +1,377 @@
+use crate::widgets::Chart;
+use crate::{Color, LvError, LvResult, NativeObject, Point};
+use core::ptr::NonNull;
+
+/// How new points are added to the series of a chart.
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub enum ChartUpdateMode {
+    /// Shift the old points to the left and add the new one to the right
+    Shift,
+    /// Add the new point after the last one, starting again from the left at the end
+    Circular,
+}
+
+impl From<ChartUpdateMode> for lvgl_sys::lv_chart_update_mode_t {
+    fn from(mode: ChartUpdateMode) -> Self {
+        match mode {
+            ChartUpdateMode::Shift => lvgl_sys::LV_CHART_UPDATE_MODE_SHIFT as u8,
+            ChartUpdateMode::Circular => lvgl_sys::LV_CHART_UPDATE_MODE_CIRCULAR as u8,
+        }
+    }
+}
+
+/// The Y axis a series is plotted against.
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub enum ChartAxis {
+    PrimaryY,
+    SecondaryY,
+}
+
+impl From<ChartAxis> for lvgl_sys::lv_chart_axis_t {
+    fn from(axis: ChartAxis) -> Self {
+        match axis {
+            ChartAxis::PrimaryY => lvgl_sys::LV_CHART_AXIS_PRIMARY_Y as u8,
+            ChartAxis::SecondaryY => lvgl_sys::LV_CHART_AXIS_SECONDARY_Y as u8,
+        }
+    }
+}
+
+bitflags! {
+    /// The directions in which the lines of a cursor are drawn.
+    pub struct CursorDirection: u8 {
+        const NONE = lvgl_sys::LV_CHART_CURSOR_NONE as u8;
+        const RIGHT = lvgl_sys::LV_CHART_CURSOR_RIGHT as u8;
+        const UP = lvgl_sys::LV_CHART_CURSOR_UP as u8;
+        const LEFT = lvgl_sys::LV_CHART_CURSOR_LEFT as u8;
+        const DOWN = lvgl_sys::LV_CHART_CURSOR_DOWN as u8;
+    }
+}
+
+impl Chart {
+    /// Allocate a new data series, drawn with `color`.
+    ///
+    /// Configure the chart (type, point count, ranges) before adding series to it.
+    pub fn add_series(&mut self, color: Color) -> LvResult<ChartSeries> {
+        let raw = unsafe { lvgl_sys::lv_chart_add_series(self.core.raw()?.as_ptr(), color.raw) };
+        Ok(ChartSeries {
+            raw: NonNull::new(raw).ok_or(LvError::LvOOMemory)?,
+        })
+    }
+
+    /// Add a cursor, drawn with `color` in the given directions from its point.
+    pub fn add_cursor(&mut self, color: Color, dir: CursorDirection) -> LvResult<ChartCursor> {
+        let chart = self.core.raw()?.as_ptr();
+        let raw = unsafe { lvgl_sys::lv_chart_add_cursor(chart, color.raw, dir.bits()) };
+        Ok(ChartCursor {
+            raw: NonNull::new(raw).ok_or(LvError::LvOOMemory)?,
+        })
+    }
+
+    /// Set how new points are added by `ChartSeries::push`.
+    pub fn set_update_mode(&mut self, mode: ChartUpdateMode) -> LvResult<()> {
+        unsafe {
+            lvgl_sys::lv_chart_set_update_mode(self.core.raw()?.as_mut(), mode.into());
+        }
+        Ok(())
+    }
+
+    /// Set the number of points of every series. The point count can't be changed once a
+    /// series uses an external array, see `ChartSeries::set_ext_array`.
+    pub fn set_point_count(&mut self, count: u16) -> LvResult<()> {
+        unsafe {
+            let chart = self.core.raw()?.as_ptr();
+            if count != lvgl_sys::lv_chart_get_point_count(chart) && has_ext_array(chart) {
+                return Err(LvError::InvalidReference);
+            }
+            lvgl_sys::lv_chart_set_point_count(chart, count);
+        }
+        Ok(())
+    }
+
+    /// Set the minimal and maximal values of an Y axis.
+    pub fn set_y_range(&mut self, axis: ChartAxis, min: i16, max: i16) -> LvResult<()> {
+        unsafe {
+            lvgl_sys::lv_chart_set_y_range(self.core.raw()?.as_mut(), axis.into(), min, max);
+        }
+        Ok(())
+    }
+
+    fn ext(&self) -> LvResult<&lvgl_sys::lv_chart_ext_t> {
+        unsafe {
+            let ext = lvgl_sys::lv_obj_get_ext_attr(self.core.raw()?.as_ptr());
+            Ok(&*(ext as *const lvgl_sys::lv_chart_ext_t))
+        }
+    }
+}
+
+/// A data series of a `Chart`.
+///
+/// The handle doesn't borrow the chart, which is passed to every method instead. Using it with
+/// another chart, or after it was removed, fails with `LvError::InvalidReference`.
+pub struct ChartSeries {
+    raw: NonNull<lvgl_sys::lv_chart_series_t>,
+}
+
+impl ChartSeries {
+    /// Add a point, as configured by `Chart::set_update_mode`.
+    pub fn push(&mut self, chart: &mut Chart, value: i16) -> LvResult<()> {
+        unsafe {
+            lvgl_sys::lv_chart_set_next(self.chart(chart)?, self.raw.as_ptr(), value);
+        }
+        Ok(())
+    }
+
+    /// Set the value of all the points, `points` is copied by the chart. Fails if `points` is
+    /// shorter than the point count of the chart.
+    pub fn set_points(&mut self, chart: &mut Chart, points: &[i16]) -> LvResult<()> {
+        unsafe {
+            let chart = self.chart(chart)?;
+            let count = lvgl_sys::lv_chart_get_point_count(chart);
+            if points.len() < usize::from(count) {
+                return Err(LvError::InvalidReference);
+            }
+            // LVGL only reads the points
+            lvgl_sys::lv_chart_set_points(
+                chart,
+                self.raw.as_ptr(),
+                points.as_ptr() as *mut lvgl_sys::lv_coord_t,
+            );
+        }
+        Ok(())
+    }
+
+    /// Set the value of the point at `id`.
+    pub fn set_point(&mut self, chart: &mut Chart, id: u16, value: i16) -> LvResult<()> {
+        unsafe {
+            let chart = self.chart(chart)?;
+            if id >= lvgl_sys::lv_chart_get_point_count(chart) {
+                return Err(LvError::InvalidReference);
+            }
+            lvgl_sys::lv_chart_set_point_id(chart, self.raw.as_ptr(), value, id);
+        }
+        Ok(())
+    }
+
+    /// The value of the point at `id`.
+    pub fn point(&self, chart: &Chart, id: u16) -> LvResult<i16> {
+        unsafe {
+            let chart = self.chart(chart)?;
+            if id >= lvgl_sys::lv_chart_get_point_count(chart) {
+                return Err(LvError::InvalidReference);
+            }
+            Ok(lvgl_sys::lv_chart_get_point_id(
+                chart,
+                self.raw.as_ptr(),
+                id,
+            ))
+        }
+    }
+
+    /// Use `points` as the storage of the series instead of an array allocated by LVGL. The
+    /// chart keeps reading and writing it, so its length must be the point count of the chart,
+    /// which can't be changed afterwards.
+    pub fn set_ext_array(&mut self, chart: &mut Chart, points: &'static mut [i16]) -> LvResult<()> {
+        unsafe {
+            let chart = self.chart(chart)?;
+            let count = lvgl_sys::lv_chart_get_point_count(chart);
+            if points.len() != usize::from(count) {
+                return Err(LvError::InvalidReference);
+            }
+            lvgl_sys::lv_chart_set_ext_array(chart, self.raw.as_ptr(), points.as_mut_ptr(), count);
+        }
+        Ok(())
+    }
+
+    /// Set all the points to `value`.
+    pub fn init_points(&mut self, chart: &mut Chart, value: i16) -> LvResult<()> {
+        unsafe {
+            lvgl_sys::lv_chart_init_points(self.chart(chart)?, self.raw.as_ptr(), value);
+        }
+        Ok(())
+    }
+
+    /// Clear all the points, they are not drawn until new values are set.
+    pub fn clear(&mut self, chart: &mut Chart) -> LvResult<()> {
+        unsafe {
+            lvgl_sys::lv_chart_clear_series(self.chart(chart)?, self.raw.as_ptr());
+        }
+        Ok(())
+    }
+
+    pub fn set_color(&mut self, chart: &mut Chart, color: Color) -> LvResult<()> {
+        unsafe {
+            let chart = self.chart(chart)?;
+            (*self.raw.as_ptr()).color = color.raw;
+            lvgl_sys::lv_chart_refresh(chart);
+        }
+        Ok(())
+    }
+
+    pub fn set_hidden(&mut self, chart: &mut Chart, hidden: bool) -> LvResult<()> {
+        unsafe {
+            lvgl_sys::lv_chart_hide_series(self.chart(chart)?, self.raw.as_ptr(), hidden);
+        }
+        Ok(())
+    }
+
+    /// Plot the series against the given Y axis.
+    pub fn set_axis(&mut self, chart: &mut Chart, axis: ChartAxis) -> LvResult<()> {
+        unsafe {
+            lvgl_sys::lv_chart_set_series_axis(self.chart(chart)?, self.raw.as_ptr(), axis.into());
+        }
+        Ok(())
+    }
+
+    /// Remove the series from the chart and free its points.
+    pub fn remove(self, chart: &mut Chart) -> LvResult<()> {
+        unsafe {
+            lvgl_sys::lv_chart_remove_series(self.chart(chart)?, self.raw.as_ptr());
+        }
+        Ok(())
+    }
+
+    // The native chart, if it holds this series
+    fn chart(&self, chart: &Chart) -> LvResult<*mut lvgl_sys::lv_obj_t> {
+        if ll_contains(&chart.ext()?.series_ll, self.raw.as_ptr() as *const _) {
+            Ok(chart.core.raw()?.as_ptr())
+        } else {
+            Err(LvError::InvalidReference)
+        }
+    }
+}
+
+/// A cursor of a `Chart`, highlighting a point.
+///
+/// Like `ChartSeries`, the chart is passed to every method and must be the one holding the cursor.
+pub struct ChartCursor {
+    raw: NonNull<lvgl_sys::lv_chart_cursor_t>,
+}
+
+impl ChartCursor {
+    /// Move the cursor to a point, relative to the chart.
+    pub fn set_point(&mut self, chart: &mut Chart, point: Point) -> LvResult<()> {
+        let mut point = lvgl_sys::lv_point_t::from(point);
+        unsafe {
+            lvgl_sys::lv_chart_set_cursor_point(self.chart(chart)?, self.raw.as_ptr(), &mut point);
+        }
+        Ok(())
+    }
+
+    pub fn point(&self, chart: &Chart) -> LvResult<Point> {
+        let point =
+            unsafe { lvgl_sys::lv_chart_get_cursor_point(self.chart(chart)?, self.raw.as_ptr()) };
+        Ok(Point::new(point.x, point.y))
+    }
+
+    // The native chart, if it holds this cursor
+    fn chart(&self, chart: &Chart) -> LvResult<*mut lvgl_sys::lv_obj_t> {
+        if ll_contains(&chart.ext()?.cursors_ll, self.raw.as_ptr() as *const _) {
+            Ok(chart.core.raw()?.as_ptr())
+        } else {
+            Err(LvError::InvalidReference)
+        }
+    }
+}
+
+/// Whether `node` is in the linked list `ll`.
+fn ll_contains(ll: &lvgl_sys::lv_ll_t, node: *const cty::c_void) -> bool {
+    unsafe {
+        let mut next = lvgl_sys::_lv_ll_get_head(ll) as *const cty::c_void;
+        while !next.is_null() {
+            if next == node {
+                return true;
+            }
+            next = lvgl_sys::_lv_ll_get_next(ll, next);
+        }
+    }
+    false
+}
+
+/// Whether a series of `chart` uses an external array.
+unsafe fn has_ext_array(chart: *mut lvgl_sys::lv_obj_t) -> bool {
+    let ext = &*(lvgl_sys::lv_obj_get_ext_attr(chart) as *const lvgl_sys::lv_chart_ext_t);
+    let mut series =
+        lvgl_sys::_lv_ll_get_head(&ext.series_ll) as *const lvgl_sys::lv_chart_series_t;
+    while let Some(ser) = series.as_ref() {
+        if ser.ext_buf_assigned() != 0 {
+            return true;
+        }
+        series = lvgl_sys::_lv_ll_get_next(&ext.series_ll, series as *const cty::c_void)
+            as *const lvgl_sys::lv_chart_series_t;
+    }
+    false
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::Obj;
+
+    fn new_chart(screen: &mut Obj) -> Chart {
+        let mut chart = Chart::new(screen).unwrap();
+        chart.set_point_count(4).unwrap();
+        chart
+    }
+
+    #[test]
+    fn point_count_is_fixed_by_ext_array() {
+        crate::lvgl_init();
+        static mut POINTS: [i16; 4] = [0; 4];
+        let mut screen = Obj::default();
+        let mut chart = new_chart(&mut screen);
+        let mut series = chart.add_series(Color::from_rgb((0, 0, 255))).unwrap();
+
+        assert!(chart.set_point_count(8).is_ok());
+        assert!(series
+            .set_ext_array(&mut chart, unsafe { &mut POINTS })
+            .is_err());
+        chart.set_point_count(4).unwrap();
+        series
+            .set_ext_array(&mut chart, unsafe { &mut POINTS })
+            .unwrap();
+        assert!(chart.set_point_count(8).is_err());
+        assert!(chart.set_point_count(4).is_ok());
+
+        series.set_point(&mut chart, 2, 7).unwrap();
+        assert_eq!(unsafe { POINTS[2] }, 7);
+    }
+
+    #[test]
+    fn set_and_read_points() {
+        crate::lvgl_init();
+        let mut screen = Obj::default();
+        let mut chart = new_chart(&mut screen);
+        let mut series = chart.add_series(Color::from_rgb((255, 0, 0))).unwrap();
+
+        series.set_points(&mut chart, &[1, 2, 3, 4]).unwrap();
+        assert_eq!(series.point(&chart, 3), Ok(4));
+        assert!(series.set_points(&mut chart, &[1, 2, 3]).is_err());
+
+        series.set_point(&mut chart, 0, 10).unwrap();
+        assert_eq!(series.point(&chart, 0), Ok(10));
+        assert!(series.set_point(&mut chart, 4, 10).is_err());
+        assert!(series.point(&chart, 4).is_err());
+
+        series.init_points(&mut chart, 5).unwrap();
+        assert_eq!(series.point(&chart, 1), Ok(5));
+
+        chart.set_update_mode(ChartUpdateMode::Shift).unwrap();
+        series.push(&mut chart, 9).unwrap();
+        assert_eq!(series.point(&chart, 3), Ok(9));
+    }
+
+    #[test]
+    fn series_of_another_chart_is_rejected() {
+        crate::lvgl_init();
+        let mut screen = Obj::default();
+        let mut chart = new_chart(&mut screen);
+        let mut other = new_chart(&mut screen);
+        let mut series = chart.add_series(Color::from_rgb((0, 255, 0))).unwrap();
+
+        // the chart can be configured while the series is alive
+        chart.set_y_range(ChartAxis::PrimaryY, 0, 50).unwrap();
+        assert!(series.set_point(&mut other, 0, 1).is_err());
+        assert!(series.set_point(&mut chart, 0, 1).is_ok());
+        assert!(series.remove(&mut other).is_err());
+    }
+}
@@ -0,0 +1,53 @@
+#[cfg(feature = "alloc")]
+use crate::widgets::options::selected_option;
+use crate::widgets::options::OptionList;
+use crate::widgets::Dropdown;
+use crate::{LvResult, NativeObject};
+#[cfg(feature = "alloc")]
+use alloc::string::String;
+
+impl Dropdown {
+    /// Replace the options, which are copied.
+    ///
+    /// ```ignore
+    /// dropdown.set_options(["Apple", "Banana", "Orange"])?;
+    /// ```
+    pub fn set_options<'a, I>(&mut self, options: I) -> LvResult<()>
+    where
+        I: IntoIterator<Item = &'a str>,
+    {
+        let options = OptionList::new(options)?;
+        unsafe {
+            lvgl_sys::lv_dropdown_set_options(self.core.raw()?.as_mut(), options.as_ptr());
+        }
+        Ok(())
+    }
+
+    /// Insert an option at `pos`, or after the last option if it is `None`.
+    pub fn add_option(&mut self, option: &str, pos: Option<u32>) -> LvResult<()> {
+        let option = OptionList::new(core::iter::once(option))?;
+        let pos = pos.unwrap_or(lvgl_sys::LV_DROPDOWN_POS_LAST);
+        unsafe {
+            lvgl_sys::lv_dropdown_add_option(self.core.raw()?.as_mut(), option.as_ptr(), pos);
+        }
+        Ok(())
+    }
+
+    /// The index of the selected option.
+    pub fn selected(&self) -> LvResult<usize> {
+        unsafe {
+            Ok(usize::from(lvgl_sys::lv_dropdown_get_selected(
+                self.core.raw()?.as_ptr(),
+            )))
+        }
+    }
+
+    /// A copy of the text of the selected option, with the `alloc` feature.
+    #[cfg(feature = "alloc")]
+    pub fn selected_str(&self) -> LvResult<String> {
+        unsafe {
+            let options = lvgl_sys::lv_dropdown_get_options(self.core.raw()?.as_ptr());
+            selected_option(options, self.selected()?)
+        }
+    }
+}
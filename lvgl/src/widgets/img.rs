@@ -0,0 +1,22 @@
+use crate::widgets::{BtnState, Img, Imgbtn};
+use crate::{ImageSource, LvResult, NativeObject};
+
+impl Img {
+    /// Set the image to show, the image keeps a pointer to the source.
+    pub fn set_src(&mut self, src: ImageSource) -> LvResult<()> {
+        unsafe {
+            lvgl_sys::lv_img_set_src(self.core.raw()?.as_mut(), src.as_ptr());
+        }
+        Ok(())
+    }
+}
+
+impl Imgbtn {
+    /// Set the image shown in a state of the button, the button keeps a pointer to the source.
+    pub fn set_src(&mut self, state: BtnState, src: ImageSource) -> LvResult<()> {
+        unsafe {
+            lvgl_sys::lv_imgbtn_set_src(self.core.raw()?.as_mut(), state.into(), src.as_ptr());
+        }
+        Ok(())
+    }
+}
@@ -0,0 +1,62 @@
+use crate::widgets::{ButtonMap, Keyboard, KeyboardMode, Textarea};
+use crate::{LvResult, NativeObject, Widget};
+use core::mem;
+use core::ptr::{self, NonNull};
+
+impl Keyboard {
+    /// Type into `textarea`.
+    ///
+    /// The keys are typed by the default event handler of the keyboard, which is replaced by a
+    /// handler set with `on_event`.
+    ///
+    /// # Safety
+    /// The keyboard keeps a pointer to the textarea, which LVGL does not clear when the textarea
+    /// is deleted. The keyboard must be detached with `clear_textarea`, or deleted, before the
+    /// textarea is.
+    pub unsafe fn set_textarea(&mut self, textarea: &mut Textarea) -> LvResult<()> {
+        lvgl_sys::lv_keyboard_set_textarea(self.core.raw()?.as_mut(), textarea.raw()?.as_mut());
+        Ok(())
+    }
+
+    /// Stop typing into the textarea.
+    pub fn clear_textarea(&mut self) -> LvResult<()> {
+        unsafe {
+            lvgl_sys::lv_keyboard_set_textarea(self.core.raw()?.as_mut(), ptr::null_mut());
+        }
+        Ok(())
+    }
+
+    /// The textarea the keyboard types into, if there is one.
+    pub fn get_textarea(&self) -> LvResult<Option<Textarea>> {
+        unsafe {
+            let textarea = lvgl_sys::lv_keyboard_get_textarea(self.core.raw()?.as_ptr());
+            Ok(NonNull::new(textarea).map(|raw| Textarea::from_raw(raw)))
+        }
+    }
+
+    /// Use the keys of `map` in the given mode.
+    ///
+    /// LVGL shares the key maps of a mode between all the keyboards, including those created
+    /// later, so the map is kept for the rest of the program.
+    pub fn set_map(&mut self, mode: KeyboardMode, map: ButtonMap) -> LvResult<()> {
+        let (keys, ctrl) = map.as_raw()?;
+        let mode = u8::from(mode);
+        unsafe {
+            let raw = self.core.raw()?.as_ptr();
+            // Setting the map of the current mode reloads it before its control bits are set,
+            // with a different number of keys. Use another mode meanwhile.
+            let current = lvgl_sys::lv_keyboard_get_mode(raw);
+            let other = if u32::from(mode) == lvgl_sys::LV_KEYBOARD_MODE_NUM {
+                lvgl_sys::LV_KEYBOARD_MODE_TEXT_LOWER
+            } else {
+                lvgl_sys::LV_KEYBOARD_MODE_NUM
+            };
+            lvgl_sys::lv_keyboard_set_mode(raw, other as lvgl_sys::lv_keyboard_mode_t);
+            lvgl_sys::lv_keyboard_set_map(raw, mode, keys);
+            lvgl_sys::lv_keyboard_set_ctrl_map(raw, mode, ctrl);
+            lvgl_sys::lv_keyboard_set_mode(raw, current);
+        }
+        mem::forget(map);
+        Ok(())
+    }
+}
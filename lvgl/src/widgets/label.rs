@@ -1,4 +1,4 @@
-use crate::widgets::Label;
+use crate::widgets::{Label, LabelAlign};
 use crate::{LvResult, NativeObject};
 
 impl Label {
@@ -9,12 +9,3 @@ impl Label {
         Ok(())
     }
 }
-
-#[derive(Debug, Copy, Clone, PartialEq)]
-#[repr(u8)]
-pub enum LabelAlign {
-    Left = lvgl_sys::LV_LABEL_ALIGN_LEFT as u8,
-    Center = lvgl_sys::LV_LABEL_ALIGN_CENTER as u8,
-    Right = lvgl_sys::LV_LABEL_ALIGN_RIGHT as u8,
-    Auto = lvgl_sys::LV_LABEL_ALIGN_AUTO as u8,
-}
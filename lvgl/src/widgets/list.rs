@@ -0,0 +1,141 @@
+use crate::support::event_callback;
+use crate::widgets::{Img, Label, List};
+use crate::{Animation, Event, ImageSource, LvError, LvResult, NativeObject, Obj, Part, Widget};
+use core::marker::PhantomData;
+use core::ptr::{self, NonNull};
+use cstr_core::CStr;
+
+/// A button of a `List`, returned by `List::add_item`.
+///
+/// The item borrows the list, so it can't be used once the list is changed with `remove` or
+/// `clean`, which delete items.
+pub struct ListItem<'a> {
+    core: Obj,
+    _list: PhantomData<&'a List>,
+}
+
+impl List {
+    /// Add a button at the end of the list, with an optional icon on the left of its text.
+    pub fn add_item(&self, icon: Option<ImageSource>, text: &CStr) -> LvResult<ListItem<'_>> {
+        let icon = icon.map_or(ptr::null(), |icon| icon.as_ptr());
+        unsafe {
+            let btn = lvgl_sys::lv_list_add_btn(self.core.raw()?.as_mut(), icon, text.as_ptr());
+            let btn = NonNull::new(btn).ok_or(LvError::LvOOMemory)?;
+            Ok(ListItem::from_raw(btn))
+        }
+    }
+
+    /// Delete the item at `index`, no item of the list can be borrowed meanwhile.
+    pub fn remove(&mut self, index: u16) -> LvResult<()> {
+        if unsafe { lvgl_sys::lv_list_remove(self.core.raw()?.as_ptr(), index) } {
+            Ok(())
+        } else {
+            Err(LvError::InvalidReference)
+        }
+    }
+
+    /// The item at `index`, if there is one.
+    pub fn item(&self, index: u16) -> LvResult<Option<ListItem<'_>>> {
+        let list = self.core.raw()?.as_ptr();
+        let mut btn = ptr::null_mut();
+        for _ in 0..=index {
+            btn = unsafe { lvgl_sys::lv_list_get_next_btn(list, btn) };
+            if btn.is_null() {
+                return Ok(None);
+            }
+        }
+        Ok(NonNull::new(btn).map(|btn| unsafe { ListItem::from_raw(btn) }))
+    }
+
+    /// The index of `item`, `None` if it is not in this list.
+    pub fn index_of(&self, item: &ListItem<'_>) -> LvResult<Option<u16>> {
+        let index = unsafe {
+            lvgl_sys::lv_list_get_btn_index(self.core.raw()?.as_ptr(), item.raw()?.as_ptr())
+        };
+        Ok(core::convert::TryFrom::try_from(index).ok())
+    }
+
+    /// Select `item` for the keys of an input device group, or nothing with `None`.
+    pub fn focus_item(&self, item: Option<&ListItem<'_>>) -> LvResult<()> {
+        let btn = match item {
+            Some(item) => item.raw()?.as_ptr(),
+            None => ptr::null_mut(),
+        };
+        unsafe {
+            lvgl_sys::lv_list_focus_btn(self.core.raw()?.as_mut(), btn);
+        }
+        Ok(())
+    }
+
+    /// The item selected with the keys of an input device group.
+    pub fn selected_item(&self) -> LvResult<Option<ListItem<'_>>> {
+        let btn = unsafe { lvgl_sys::lv_list_get_btn_selected(self.core.raw()?.as_ptr()) };
+        Ok(NonNull::new(btn).map(|btn| unsafe { ListItem::from_raw(btn) }))
+    }
+
+    /// Scroll the list to show `item`.
+    pub fn scroll_to(&self, item: &ListItem<'_>, anim: Animation) -> LvResult<()> {
+        unsafe {
+            lvgl_sys::lv_list_focus(item.raw()?.as_ptr(), anim.into());
+        }
+        Ok(())
+    }
+}
+
+impl<'a> ListItem<'a> {
+    /// Handle the events of the item, e.g. `Event::Clicked`.
+    ///
+    /// LVGL keeps the handler until the item is deleted, so it can't borrow anything. The item it
+    /// is called with doesn't borrow the list and is only valid during the call.
+    pub fn on_event<F>(&mut self, f: F) -> LvResult<()>
+    where
+        F: FnMut(ListItem<'static>, Event<()>) + 'static,
+    {
+        unsafe {
+            let obj = self.core.raw()?.as_mut();
+            let user_closure = crate::Box::new(f);
+            obj.user_data = crate::Box::into_raw(user_closure) as *mut cty::c_void;
+            lvgl_sys::lv_obj_set_event_cb(obj, Some(event_callback::<ListItem<'static>, F>));
+        }
+        Ok(())
+    }
+
+    /// The text of the item.
+    pub fn text(&self) -> LvResult<&CStr> {
+        let text = unsafe { lvgl_sys::lv_list_get_btn_text(self.core.raw()?.as_ptr()) };
+        if text.is_null() {
+            return Err(LvError::InvalidReference);
+        }
+        Ok(unsafe { CStr::from_ptr(text) })
+    }
+
+    /// The label showing the text of the item, e.g. to change the text.
+    pub fn label(&self) -> LvResult<Option<Label>> {
+        let label = unsafe { lvgl_sys::lv_list_get_btn_label(self.core.raw()?.as_ptr()) };
+        Ok(NonNull::new(label).map(|label| unsafe { Label::from_raw(label) }))
+    }
+
+    /// The image showing the icon of the item, if it has one.
+    pub fn icon(&self) -> LvResult<Option<Img>> {
+        let img = unsafe { lvgl_sys::lv_list_get_btn_img(self.core.raw()?.as_ptr()) };
+        Ok(NonNull::new(img).map(|img| unsafe { Img::from_raw(img) }))
+    }
+}
+
+impl NativeObject for ListItem<'_> {
+    fn raw(&self) -> LvResult<NonNull<lvgl_sys::lv_obj_t>> {
+        self.core.raw()
+    }
+}
+
+impl Widget for ListItem<'_> {
+    type SpecialEvent = ();
+    type Part = Part;
+
+    unsafe fn from_raw(raw: NonNull<lvgl_sys::lv_obj_t>) -> Self {
+        Self {
+            core: Obj::from_raw(raw),
+            _list: PhantomData,
+        }
+    }
+}
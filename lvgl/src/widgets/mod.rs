@@ -1,12 +1,25 @@
 mod arc;
-mod bar;
-mod gauge;
+mod btnmatrix;
+mod canvas;
+mod chart;
+mod dropdown;
+mod img;
+mod keyboard;
 mod label;
+mod list;
+mod options;
+mod roller;
+mod tabview;
+mod textarea;
+mod tileview;
+
+pub use btnmatrix::*;
+pub use chart::*;
+pub use list::*;
+pub use tabview::*;
+pub use textarea::*;
+pub use tileview::*;
 
 include!(concat!(env!("OUT_DIR"), "/generated.rs"));
 
 use crate::NativeObject;
-pub use arc::*;
-pub use bar::*;
-pub use gauge::*;
-pub use label::*;
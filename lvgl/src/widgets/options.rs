@@ -0,0 +1,134 @@
+use crate::{LvError, LvResult};
+#[cfg(feature = "alloc")]
+use alloc::string::String;
+use core::ptr::{self, NonNull};
+use cstr_core::CStr;
+
+/// The options of a dropdown or a roller, joined by `'\n'` in a C string in LVGL memory. LVGL
+/// copies the options it is given, this is only the temporary argument.
+pub(super) struct OptionList {
+    raw: NonNull<cty::c_char>,
+}
+
+impl OptionList {
+    /// Join `options`, which can't contain a `'\n'` or a nul character.
+    pub(super) fn new<'a, I>(options: I) -> LvResult<Self>
+    where
+        I: IntoIterator<Item = &'a str>,
+    {
+        let mut list = Self::alloc(1)?;
+        let mut len = 0;
+        for (i, option) in options.into_iter().enumerate() {
+            if option.contains(&['\n', '\0'][..]) {
+                return Err(LvError::InvalidReference);
+            }
+            let separator = if i > 0 { 1 } else { 0 };
+            list.grow(len + separator + option.len() + 1)?;
+            unsafe {
+                let end = list.raw.as_ptr().add(len) as *mut u8;
+                if separator > 0 {
+                    end.write(b'\n');
+                }
+                ptr::copy_nonoverlapping(option.as_ptr(), end.add(separator), option.len());
+            }
+            len += separator + option.len();
+        }
+        unsafe {
+            list.raw.as_ptr().add(len).write(0);
+        }
+        Ok(list)
+    }
+
+    fn alloc(size: usize) -> LvResult<Self> {
+        let raw = unsafe { lvgl_sys::lv_mem_alloc(size as lvgl_sys::size_t) };
+        let raw = NonNull::new(raw as *mut cty::c_char).ok_or(LvError::LvOOMemory)?;
+        Ok(Self { raw })
+    }
+
+    fn grow(&mut self, size: usize) -> LvResult<()> {
+        let raw = unsafe {
+            lvgl_sys::lv_mem_realloc(
+                self.raw.as_ptr() as *mut cty::c_void,
+                size as lvgl_sys::size_t,
+            )
+        };
+        self.raw = NonNull::new(raw as *mut cty::c_char).ok_or(LvError::LvOOMemory)?;
+        Ok(())
+    }
+
+    pub(super) fn as_ptr(&self) -> *const cty::c_char {
+        self.raw.as_ptr()
+    }
+}
+
+impl Drop for OptionList {
+    fn drop(&mut self) {
+        unsafe {
+            lvgl_sys::lv_mem_free(self.raw.as_ptr() as *const cty::c_void);
+        }
+    }
+}
+
+/// A copy of the option at `index` of `options`, the `'\n'` separated options of a widget.
+#[cfg(feature = "alloc")]
+pub(super) unsafe fn selected_option(
+    options: *const cty::c_char,
+    index: usize,
+) -> LvResult<String> {
+    if options.is_null() {
+        return Err(LvError::InvalidReference);
+    }
+    nth_option(CStr::from_ptr(options), index).map(String::from)
+}
+
+/// The option at `index` of the `'\n'` separated `options`.
+#[cfg(any(feature = "alloc", test))]
+fn nth_option(options: &CStr, index: usize) -> LvResult<&str> {
+    options
+        .to_str()
+        .map_err(|_| LvError::InvalidReference)?
+        .split('\n')
+        .nth(index)
+        .ok_or(LvError::InvalidReference)
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    fn joined(options: &[&str]) -> LvResult<Vec<u8>> {
+        let list = OptionList::new(options.iter().copied())?;
+        Ok(unsafe { CStr::from_ptr(list.as_ptr()) }.to_bytes().to_vec())
+    }
+
+    #[test]
+    fn options_are_joined_by_new_lines() {
+        crate::lvgl_init();
+
+        assert_eq!(
+            joined(&["One", "Two", "Three"]).unwrap(),
+            b"One\nTwo\nThree"
+        );
+        assert_eq!(joined(&["One"]).unwrap(), b"One");
+        assert_eq!(joined(&[]).unwrap(), b"");
+        // Empty options are kept, the last one as a trailing separator
+        assert_eq!(joined(&["", "Two", ""]).unwrap(), b"\nTwo\n");
+    }
+
+    #[test]
+    fn options_with_separators_are_rejected() {
+        crate::lvgl_init();
+
+        assert!(joined(&["One\nTwo"]).is_err());
+        assert!(joined(&["One", "Two\0"]).is_err());
+    }
+
+    #[test]
+    fn nth_option_splits_on_new_lines() {
+        let options = CStr::from_bytes_with_nul(b"One\nTwo\n\0").unwrap();
+        assert_eq!(nth_option(options, 0), Ok("One"));
+        assert_eq!(nth_option(options, 1), Ok("Two"));
+        assert_eq!(nth_option(options, 2), Ok(""));
+        assert!(nth_option(options, 3).is_err());
+    }
+}
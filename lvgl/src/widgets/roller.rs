@@ -0,0 +1,45 @@
+#[cfg(feature = "alloc")]
+use crate::widgets::options::selected_option;
+use crate::widgets::options::OptionList;
+use crate::widgets::{Roller, RollerMode};
+use crate::{LvResult, NativeObject};
+#[cfg(feature = "alloc")]
+use alloc::string::String;
+
+impl Roller {
+    /// Replace the options, which are copied. With `RollerMode::Infinite` the first option
+    /// follows the last one.
+    pub fn set_options<'a, I>(&mut self, options: I, mode: RollerMode) -> LvResult<()>
+    where
+        I: IntoIterator<Item = &'a str>,
+    {
+        let options = OptionList::new(options)?;
+        unsafe {
+            lvgl_sys::lv_roller_set_options(
+                self.core.raw()?.as_mut(),
+                options.as_ptr(),
+                mode.into(),
+            );
+        }
+        Ok(())
+    }
+
+    /// The index of the selected option.
+    pub fn selected(&self) -> LvResult<usize> {
+        unsafe {
+            Ok(usize::from(lvgl_sys::lv_roller_get_selected(
+                self.core.raw()?.as_ptr(),
+            )))
+        }
+    }
+
+    /// A copy of the text of the selected option, with the `alloc` feature.
+    #[cfg(feature = "alloc")]
+    pub fn selected_str(&self) -> LvResult<String> {
+        // In infinite mode the options are repeated, the index is the one of the first copy
+        unsafe {
+            let options = lvgl_sys::lv_roller_get_options(self.core.raw()?.as_ptr());
+            selected_option(options, self.selected()?)
+        }
+    }
+}
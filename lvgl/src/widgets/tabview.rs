@@ -0,0 +1,70 @@
+use crate::support::event_index;
+use crate::widgets::{Page, Tabview};
+use crate::{LvError, LvResult, NativeObject, Widget, WidgetEvent};
+use core::ptr::NonNull;
+use cstr_core::CStr;
+
+/// The events of a `Tabview`, in `Event::Special`.
+pub enum TabviewEvent {
+    /// Another tab was selected, with the index of the tab
+    ValueChanged(u16),
+}
+
+impl WidgetEvent for TabviewEvent {
+    unsafe fn from_raw(
+        _obj: NonNull<lvgl_sys::lv_obj_t>,
+        event: lvgl_sys::lv_event_t,
+    ) -> Option<Self> {
+        match u32::from(event) {
+            lvgl_sys::LV_EVENT_VALUE_CHANGED => event_index().map(TabviewEvent::ValueChanged),
+            _ => None,
+        }
+    }
+}
+
+impl Tabview {
+    /// Add a tab at the end, with a button showing `name`, which is copied. The content of the
+    /// tab is added to the returned page.
+    pub fn add_tab(&mut self, name: &CStr) -> LvResult<Page> {
+        unsafe {
+            let page = lvgl_sys::lv_tabview_add_tab(self.core.raw()?.as_mut(), name.as_ptr());
+            let page = NonNull::new(page).ok_or(LvError::LvOOMemory)?;
+            Ok(Page::from_raw(page))
+        }
+    }
+
+    /// The page of the tab at `index`, if there is one.
+    pub fn tab(&self, index: u16) -> LvResult<Option<Page>> {
+        let page = unsafe { lvgl_sys::lv_tabview_get_tab(self.core.raw()?.as_ptr(), index) };
+        Ok(NonNull::new(page).map(|page| unsafe { Page::from_raw(page) }))
+    }
+
+    /// Rename the tab at `index`, the name is copied.
+    pub fn set_tab_name(&mut self, index: u16, name: &CStr) -> LvResult<()> {
+        let raw = self.core.raw()?.as_ptr();
+        if index >= unsafe { lvgl_sys::lv_tabview_get_tab_count(raw) } {
+            return Err(LvError::InvalidReference);
+        }
+        unsafe {
+            lvgl_sys::lv_tabview_set_tab_name(raw, index, name.as_ptr() as *mut cty::c_char);
+        }
+        Ok(())
+    }
+
+    /// Delete the content of a tab, `tab` is a page returned by `add_tab` or `tab`. Fails if it
+    /// is not a tab of this tabview.
+    ///
+    /// The objects of the tab are deleted, their handles must not be used afterwards.
+    pub fn clean_tab(&mut self, tab: &mut Page) -> LvResult<()> {
+        let raw = self.core.raw()?.as_ptr();
+        let page = tab.raw()?.as_ptr();
+        let count = unsafe { lvgl_sys::lv_tabview_get_tab_count(raw) };
+        if !(0..count).any(|i| unsafe { lvgl_sys::lv_tabview_get_tab(raw, i) } == page) {
+            return Err(LvError::InvalidReference);
+        }
+        unsafe {
+            lvgl_sys::lv_tabview_clean_tab(page);
+        }
+        Ok(())
+    }
+}
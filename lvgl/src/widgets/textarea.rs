@@ -0,0 +1,218 @@
+use crate::widgets::{LabelAlign, Textarea};
+use crate::{Box, LvError, LvResult, NativeObject, WidgetEvent};
+use core::ptr::{self, NonNull};
+use cstr_core::CStr;
+
+/// The events of a `Textarea`, in `Event::Special`. Inserted texts are filtered with
+/// `Textarea::set_insert_filter`.
+pub enum TextareaEvent {
+    /// The text has changed
+    ValueChanged,
+}
+
+impl WidgetEvent for TextareaEvent {
+    unsafe fn from_raw(
+        _obj: NonNull<lvgl_sys::lv_obj_t>,
+        event: lvgl_sys::lv_event_t,
+    ) -> Option<Self> {
+        match u32::from(event) {
+            lvgl_sys::LV_EVENT_VALUE_CHANGED => Some(TextareaEvent::ValueChanged),
+            _ => None,
+        }
+    }
+}
+
+/// A text about to be inserted in a `Textarea`, by typing or by `add_text` and `add_char`.
+///
+/// It is only valid in the insert filter it is sent to. The text is inserted unless it is
+/// rejected or replaced.
+pub struct TextInsert<'a> {
+    textarea: NonNull<lvgl_sys::lv_obj_t>,
+    text: *const cty::c_char,
+    replacement: &'a mut Replacement,
+}
+
+impl<'a> TextInsert<'a> {
+    /// The text to insert, e.g. a single character typed on a keyboard.
+    pub fn text(&self) -> &CStr {
+        unsafe { CStr::from_ptr(self.text) }
+    }
+
+    /// Insert nothing.
+    pub fn reject(&mut self) {
+        unsafe {
+            lvgl_sys::lv_textarea_set_insert_replace(
+                self.textarea.as_ptr(),
+                b"\0".as_ptr() as *const cty::c_char,
+            );
+        }
+    }
+
+    /// Insert `text` instead, the replacement does not go through the filter again.
+    pub fn replace(&mut self, text: &CStr) -> LvResult<()> {
+        let bytes = text.to_bytes_with_nul();
+        unsafe {
+            let copy = lvgl_sys::lv_mem_alloc(bytes.len() as lvgl_sys::size_t) as *mut cty::c_char;
+            if copy.is_null() {
+                return Err(LvError::LvOOMemory);
+            }
+            ptr::copy_nonoverlapping(bytes.as_ptr(), copy as *mut u8, bytes.len());
+            self.replacement.set(copy);
+            lvgl_sys::lv_textarea_set_insert_replace(self.textarea.as_ptr(), copy);
+        }
+        Ok(())
+    }
+}
+
+// The last replacement of an inserted text. LVGL reads it after the filter returns, so it is
+// kept until the next replacement or until the textarea is deleted.
+struct Replacement(*mut cty::c_char);
+
+impl Replacement {
+    fn set(&mut self, text: *mut cty::c_char) {
+        self.free();
+        self.0 = text;
+    }
+
+    fn free(&mut self) {
+        if !self.0.is_null() {
+            unsafe {
+                lvgl_sys::lv_mem_free(self.0 as *const cty::c_void);
+            }
+            self.0 = ptr::null_mut();
+        }
+    }
+}
+
+impl Drop for Replacement {
+    fn drop(&mut self) {
+        self.free();
+    }
+}
+
+// The state of an insert filter, in the `user_data` of the textarea.
+struct InsertFilter<F> {
+    filter: F,
+    replacement: Replacement,
+}
+
+impl Textarea {
+    /// Call `filter` with each text about to be inserted, to accept, reject or replace it.
+    ///
+    /// ```ignore
+    /// textarea.set_insert_filter(|insert| {
+    ///     if insert.text().to_bytes() == b"\n" {
+    ///         insert.reject();
+    ///     }
+    /// })?;
+    /// ```
+    ///
+    /// The filter is the event handler of the textarea: it replaces the handler set with
+    /// `on_event`, and is replaced by it.
+    pub fn set_insert_filter<F>(&mut self, filter: F) -> LvResult<()>
+    where
+        F: FnMut(&mut TextInsert<'_>) + 'static,
+    {
+        unsafe {
+            let obj = self.core.raw()?.as_mut();
+            let state = Box::new(InsertFilter {
+                filter,
+                replacement: Replacement(ptr::null_mut()),
+            });
+            obj.user_data = Box::into_raw(state) as *mut cty::c_void;
+            lvgl_sys::lv_obj_set_event_cb(obj, Some(insert_filter_callback::<F>));
+        }
+        Ok(())
+    }
+
+    pub fn set_text_align(&mut self, align: LabelAlign) -> LvResult<()> {
+        unsafe {
+            lvgl_sys::lv_textarea_set_text_align(self.core.raw()?.as_mut(), align as u8);
+        }
+        Ok(())
+    }
+}
+
+unsafe extern "C" fn insert_filter_callback<F>(
+    obj: *mut lvgl_sys::lv_obj_t,
+    event: lvgl_sys::lv_event_t,
+) where
+    F: FnMut(&mut TextInsert<'_>),
+{
+    let textarea = match NonNull::new(obj) {
+        Some(textarea) => textarea,
+        None => return,
+    };
+    let state = (*obj).user_data as *mut InsertFilter<F>;
+    match u32::from(event) {
+        lvgl_sys::LV_EVENT_INSERT => {
+            let text = lvgl_sys::lv_event_get_data() as *const cty::c_char;
+            let state = &mut *state;
+            // The replacement is inserted without being filtered again
+            if text.is_null() || text == state.replacement.0 as *const cty::c_char {
+                return;
+            }
+            let mut insert = TextInsert {
+                textarea,
+                text,
+                replacement: &mut state.replacement,
+            };
+            (state.filter)(&mut insert);
+        }
+        lvgl_sys::LV_EVENT_DELETE => {
+            (*obj).user_data = ptr::null_mut();
+            drop(Box::from_raw(state));
+        }
+        _ => {}
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::Obj;
+
+    fn cstr(bytes: &[u8]) -> &CStr {
+        CStr::from_bytes_with_nul(bytes).unwrap()
+    }
+
+    #[test]
+    fn filter_rejects_and_replaces_texts() {
+        crate::lvgl_init();
+        let mut screen = Obj::default();
+        let mut textarea = Textarea::new(&mut screen).unwrap();
+        textarea.set_text(cstr(b"\0")).unwrap();
+        textarea
+            .set_insert_filter(|insert| match insert.text().to_bytes() {
+                b"\n" => insert.reject(),
+                b"a" => insert.replace(cstr(b"AA\0")).unwrap(),
+                _ => {}
+            })
+            .unwrap();
+
+        textarea.add_text(cstr(b"x\0")).unwrap();
+        textarea.add_text(cstr(b"\n\0")).unwrap();
+        textarea.add_text(cstr(b"a\0")).unwrap();
+        textarea.add_text(cstr(b"a\0")).unwrap();
+        assert_eq!(textarea.get_text().unwrap().to_bytes(), b"xAAAA");
+    }
+
+    #[test]
+    fn replacement_is_not_filtered_again() {
+        crate::lvgl_init();
+        let mut screen = Obj::default();
+        let mut textarea = Textarea::new(&mut screen).unwrap();
+        textarea.set_text(cstr(b"\0")).unwrap();
+        let mut calls = 0;
+        textarea
+            .set_insert_filter(move |insert| {
+                calls += 1;
+                assert_eq!(calls, 1);
+                insert.replace(cstr(b"b\0")).unwrap();
+            })
+            .unwrap();
+
+        textarea.add_char(u32::from(b'a')).unwrap();
+        assert_eq!(textarea.get_text().unwrap().to_bytes(), b"b");
+    }
+}
@@ -0,0 +1,33 @@
+use crate::support::event_index;
+use crate::widgets::Tileview;
+use crate::{LvResult, NativeObject, Point, WidgetEvent};
+use core::ptr::NonNull;
+
+/// The events of a `Tileview`, in `Event::Special`.
+pub enum TileviewEvent {
+    /// Another tile was shown, with the index of its position in the valid positions
+    ValueChanged(u16),
+}
+
+impl WidgetEvent for TileviewEvent {
+    unsafe fn from_raw(
+        _obj: NonNull<lvgl_sys::lv_obj_t>,
+        event: lvgl_sys::lv_event_t,
+    ) -> Option<Self> {
+        match u32::from(event) {
+            lvgl_sys::LV_EVENT_VALUE_CHANGED => event_index().map(TileviewEvent::ValueChanged),
+            _ => None,
+        }
+    }
+}
+
+impl Tileview {
+    /// The position of the tile shown, in tiles.
+    pub fn tile_act(&self) -> LvResult<Point> {
+        let (mut x, mut y) = (0, 0);
+        unsafe {
+            lvgl_sys::lv_tileview_get_tile_act(self.core.raw()?.as_ptr(), &mut x, &mut y);
+        }
+        Ok(Point::new(x, y))
+    }
+}